@@ -0,0 +1,146 @@
+//! Benchmark: bit-packed bools/small enums vs bincode
+//!
+//! Goal: quantify the size and speed tradeoff of `limcode::bitpack`'s `BitEncoder`/`BitDecoder`
+//! against bincode's byte-per-bool, 4-byte-per-discriminant layout for a struct dominated by
+//! flags and small enums (the shape of typical transaction metadata).
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use limcode::bitpack::{bits_for_variants, BitDecoder, BitEncoder};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+enum Status {
+    Pending,
+    Confirmed,
+    Finalized,
+    Failed,
+    Dropped,
+}
+
+impl Status {
+    fn discriminant(self) -> u64 {
+        self as u64
+    }
+
+    fn from_discriminant(d: u64) -> Self {
+        match d {
+            0 => Status::Pending,
+            1 => Status::Confirmed,
+            2 => Status::Finalized,
+            3 => Status::Failed,
+            _ => Status::Dropped,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+struct TxMeta {
+    is_vote: bool,
+    is_signed: bool,
+    is_writable: bool,
+    is_fee_payer: bool,
+    is_program: bool,
+    is_sysvar: bool,
+    status: Status,
+}
+
+fn create_records(count: usize) -> Vec<TxMeta> {
+    (0..count)
+        .map(|i| TxMeta {
+            is_vote: i % 7 == 0,
+            is_signed: i % 2 == 0,
+            is_writable: i % 3 == 0,
+            is_fee_payer: i % 11 == 0,
+            is_program: i % 13 == 0,
+            is_sysvar: i % 17 == 0,
+            status: Status::from_discriminant((i % 5) as u64),
+        })
+        .collect()
+}
+
+fn pack_records(records: &[TxMeta]) -> (Vec<u8>, u64) {
+    let status_bits = bits_for_variants(5);
+    let mut enc = BitEncoder::new();
+    for r in records {
+        enc.write_bits(r.is_vote as u64, 1);
+        enc.write_bits(r.is_signed as u64, 1);
+        enc.write_bits(r.is_writable as u64, 1);
+        enc.write_bits(r.is_fee_payer as u64, 1);
+        enc.write_bits(r.is_program as u64, 1);
+        enc.write_bits(r.is_sysvar as u64, 1);
+        enc.write_bits(r.status.discriminant(), status_bits);
+    }
+    enc.finish()
+}
+
+fn unpack_records(bytes: &[u8], total_bits: u64, count: usize) -> Vec<TxMeta> {
+    let status_bits = bits_for_variants(5);
+    let mut dec = BitDecoder::new(bytes, total_bits);
+    (0..count)
+        .map(|_| TxMeta {
+            is_vote: dec.read_bits(1).unwrap() != 0,
+            is_signed: dec.read_bits(1).unwrap() != 0,
+            is_writable: dec.read_bits(1).unwrap() != 0,
+            is_fee_payer: dec.read_bits(1).unwrap() != 0,
+            is_program: dec.read_bits(1).unwrap() != 0,
+            is_sysvar: dec.read_bits(1).unwrap() != 0,
+            status: Status::from_discriminant(dec.read_bits(status_bits).unwrap()),
+        })
+        .collect()
+}
+
+fn bench_size(c: &mut Criterion) {
+    let records = create_records(1000);
+    let bincode_bytes = bincode::serialize(&records).unwrap();
+    let (packed_bytes, _) = pack_records(&records);
+
+    // Not a meaningful timing - each closure just returns a precomputed length, so this group
+    // exists only so the criterion report surfaces both encoded sizes side by side.
+    let mut group = c.benchmark_group("bitpack_size");
+    group.bench_function("bincode_bytes", |b| {
+        b.iter(|| black_box(bincode_bytes.len()))
+    });
+    group.bench_function("bitpack_bytes", |b| {
+        b.iter(|| black_box(packed_bytes.len()))
+    });
+    group.finish();
+}
+
+fn bench_serialize(c: &mut Criterion) {
+    let records = create_records(1000);
+    let size = bincode::serialize(&records).unwrap().len();
+
+    let mut group = c.benchmark_group("bitpack_serialize");
+    group.throughput(Throughput::Elements(records.len() as u64));
+
+    group.bench_function("bincode", |b| {
+        b.iter(|| bincode::serialize(black_box(&records)).unwrap())
+    });
+
+    group.bench_function("bitpack", |b| b.iter(|| pack_records(black_box(&records))));
+
+    group.throughput(Throughput::Bytes(size as u64));
+    group.finish();
+}
+
+fn bench_deserialize(c: &mut Criterion) {
+    let records = create_records(1000);
+    let bincode_bytes = bincode::serialize(&records).unwrap();
+    let (packed_bytes, total_bits) = pack_records(&records);
+
+    let mut group = c.benchmark_group("bitpack_deserialize");
+    group.throughput(Throughput::Elements(records.len() as u64));
+
+    group.bench_function("bincode", |b| {
+        b.iter(|| bincode::deserialize::<Vec<TxMeta>>(black_box(&bincode_bytes)).unwrap())
+    });
+
+    group.bench_function("bitpack", |b| {
+        b.iter(|| unpack_records(black_box(&packed_bytes), total_bits, records.len()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_size, bench_serialize, bench_deserialize);
+criterion_main!(benches);
@@ -58,6 +58,26 @@ fn bench_parallel_serialization(c: &mut Criterion) {
                 b.iter(|| bincode::serialize(black_box(txs)).unwrap())
             },
         );
+
+        group.bench_with_input(
+            BenchmarkId::new("parallel_deserialize", size),
+            &serialized,
+            |b, bytes| {
+                b.iter(|| limcode::deserialize_vec_parallel::<Transaction>(black_box(bytes)).unwrap())
+            },
+        );
+
+        // `Transaction` is dominated by small collection-length prefixes (accounts has 10
+        // entries, signature/data are fixed-size but still length-prefixed), so this is the
+        // many-small-collection workload where `serialize_varint`'s variable-width lengths
+        // should pay off most versus `serialize`'s fixed 8-byte lengths.
+        group.bench_with_input(
+            BenchmarkId::new("varint", size),
+            &transactions,
+            |b, txs| {
+                b.iter(|| limcode::serialize_varint(black_box(txs)).unwrap())
+            },
+        );
     }
 
     group.finish();
@@ -104,6 +124,15 @@ fn bench_vec_u64(c: &mut Criterion) {
                 b.iter(|| bincode::serialize(black_box(d)).unwrap())
             },
         );
+
+        let serialized = limcode::serialize(&data).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("parallel_deserialize", size),
+            &serialized,
+            |b, bytes| {
+                b.iter(|| limcode::deserialize_vec_parallel::<u64>(black_box(bytes)).unwrap())
+            },
+        );
     }
 
     group.finish();
@@ -0,0 +1,39 @@
+//! Benchmark: borrowed zero-copy POD decode vs owned decode
+//!
+//! `deserialize_pod_borrowed` returns a slice view directly into the input buffer - no
+//! allocation, no copy - while `deserialize_pod` copies that view into an owned `Vec<T>`.
+//! `Bencher::iter` alone can't tell these two apart: a loop whose result is dropped on every
+//! iteration is free for LLVM to elide, so a naive owned-path benchmark can end up measuring
+//! nothing at all. `iter_with_large_drop` avoids that by batching the drops outside the timed
+//! region, so the owned path's allocation/copy cost is actually charged, while the borrowed path
+//! (which allocates nothing to drop) is measured with plain `iter`. That's what backs the
+//! "zero alloc, zero copy" claim made elsewhere in this crate's examples with a reproducible
+//! number instead of a loop the optimizer collapsed.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn bench_pod_decode_borrowed_vs_owned(c: &mut Criterion) {
+    let sizes = [64, 1024, 16384, 262144];
+    let mut group = c.benchmark_group("pod_decode_u64_borrowed_vs_owned");
+
+    for size in sizes {
+        let data: Vec<u64> = (0..size as u64).collect();
+        let bytes = limcode::serializer::serialize_pod(&data).unwrap();
+        group.throughput(Throughput::Bytes((size * 8) as u64));
+
+        group.bench_with_input(BenchmarkId::new("borrowed", size), &bytes, |b, bytes| {
+            b.iter(|| limcode::deserializer::deserialize_pod_borrowed::<u64>(black_box(bytes)).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("owned", size), &bytes, |b, bytes| {
+            b.iter_with_large_drop(|| {
+                limcode::deserializer::deserialize_pod::<u64>(black_box(bytes)).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_pod_decode_borrowed_vs_owned);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+//! Benchmark: SIMD hex transcoding (`serialize_pod_hex`/`deserialize_pod_hex`) vs a naive
+//! byte-at-a-time hex encoder/decoder, across the same 64B-16KB size ladder used by the other
+//! Limcode-vs-baseline tables in this crate.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const HEX_DIGITS_LOWER: [u8; 16] = *b"0123456789abcdef";
+
+fn naive_hex_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        out.push(HEX_DIGITS_LOWER[(b >> 4) as usize] as char);
+        out.push(HEX_DIGITS_LOWER[(b & 0x0f) as usize] as char);
+    }
+    out
+}
+
+fn naive_hex_decode(hex: &str) -> Vec<u8> {
+    fn nibble(b: u8) -> u8 {
+        match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            _ => panic!("invalid hex byte"),
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len() / 2);
+    for pair in bytes.chunks_exact(2) {
+        out.push((nibble(pair[0]) << 4) | nibble(pair[1]));
+    }
+    out
+}
+
+fn bench_hex_encode(c: &mut Criterion) {
+    let sizes = [64, 256, 1024, 4096, 16384];
+    let mut group = c.benchmark_group("pod_hex_encode");
+
+    for size in sizes {
+        let data: Vec<u8> = (0..size).map(|i| (i % 256) as u8).collect();
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("simd", size), &data, |b, d| {
+            b.iter(|| limcode::serializer::serialize_pod_hex(black_box(d)).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("naive", size), &data, |b, d| {
+            b.iter(|| naive_hex_encode(black_box(d)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_hex_decode(c: &mut Criterion) {
+    let sizes = [64, 256, 1024, 4096, 16384];
+    let mut group = c.benchmark_group("pod_hex_decode");
+
+    for size in sizes {
+        let data: Vec<u8> = (0..size).map(|i| (i % 256) as u8).collect();
+        let hex = limcode::serializer::serialize_pod_hex(&data).unwrap();
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("simd", size), &hex, |b, h| {
+            b.iter(|| limcode::deserializer::deserialize_pod_hex::<u8>(black_box(h)).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("naive", size), &hex, |b, h| {
+            b.iter(|| naive_hex_decode(black_box(h)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_hex_encode, bench_hex_decode);
+criterion_main!(benches);
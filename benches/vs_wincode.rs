@@ -67,11 +67,18 @@ fn bench_pod_vec_u64(c: &mut Criterion) {
             b.iter(|| bincode::serialize(black_box(&data)))
         });
 
+        // Fourth column: RLP-style length-prefixed integers, which stay small for the mostly-small
+        // values `(0..size).collect()` produces instead of paying the fixed 8 bytes/element above.
+        group.bench_function("limcode_rlp_int_ser", |b| {
+            b.iter(|| limcode::rlp::serialize_rlp_int_vec(black_box(&data)))
+        });
+
         // Deserialize benchmarks
         let limcode_pod_bytes = limcode::serialize_pod(&data).unwrap();
         let limcode_serde_bytes = limcode::serialize(&data).unwrap();
         let wincode_bytes = wincode::serialize(&data).unwrap();
         let bincode_bytes = bincode::serialize(&data).unwrap();
+        let limcode_rlp_int_bytes = limcode::rlp::serialize_rlp_int_vec(&data);
 
         group.bench_function("limcode_pod_de", |b| {
             b.iter(|| limcode::deserialize_pod::<u64>(black_box(&limcode_pod_bytes)))
@@ -89,6 +96,10 @@ fn bench_pod_vec_u64(c: &mut Criterion) {
             b.iter(|| bincode::deserialize::<Vec<u64>>(black_box(&bincode_bytes)))
         });
 
+        group.bench_function("limcode_rlp_int_de", |b| {
+            b.iter(|| limcode::rlp::deserialize_rlp_int_vec(black_box(&limcode_rlp_int_bytes)))
+        });
+
         group.finish();
     }
 }
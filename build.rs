@@ -4,7 +4,6 @@ fn main() {
     // Compile C++ library
     let mut build = cc::Build::new();
 
-    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_else(|_| "unknown".to_string());
     let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_else(|_| "unknown".to_string());
 
     // Disable compiler CPU feature auto-detection to prevent SIGILL during compilation
@@ -16,37 +15,14 @@ fn main() {
         .flag_if_supported("-std=c++20")
         .flag_if_supported("-fno-builtin"); // Disable compiler builtins that might use CPU features
 
-    // Only apply x86_64 SIMD flags on x86_64 architecture
-    if target_arch == "x86_64" {
-        // In CI, use conservative baseline to avoid SIGILL on different runners
-        // In local builds, use -march=native for maximum performance
-        let is_ci = env::var("CI").is_ok()
-            || env::var("GITHUB_ACTIONS").is_ok()
-            || env::var("CONTINUOUS_INTEGRATION").is_ok();
-
-        if is_ci {
-            println!("cargo:warning=Building in CI mode with conservative CPU features (x86-64-v2)");
-            // x86-64-v2: SSE4.2, POPCNT, SSSE3 (available on all modern CI runners)
-            // Explicitly disable advanced features
-            build
-                .flag_if_supported("-march=x86-64-v2")
-                .flag_if_supported("-mno-avx512f")
-                .flag_if_supported("-mno-avx512bw")
-                .flag_if_supported("-mno-avx512dq");
-        } else {
-            println!("cargo:warning=Building in local mode with native CPU optimizations");
-            // Local builds: optimize for the actual CPU
-            build
-                .flag_if_supported("-march=native")
-                .flag_if_supported("-mavx512f")
-                .flag_if_supported("-mavx512bw")
-                .flag_if_supported("-mavx512dq")
-                .flag_if_supported("-mavx512vl")
-                .flag_if_supported("-mavx2")
-                .flag_if_supported("-msse4.2")
-                .flag_if_supported("-mbmi2");
-        }
-    }
+    // No `-march`/`-mavx*` flags here, on purpose: baking a SIMD level in at compile time means
+    // a binary built with `-march=native` can SIGILL on a different (older) machine, while the
+    // conservative CI baseline leaves performance on the table on a capable host. Instead, the
+    // scalar/SSE4.2/AVX2/AVX-512 variants of the bulk-copy routines behind
+    // `limcode_encoder_write_bytes`/`limcode_decoder_read_bytes` are all compiled in
+    // unconditionally (via per-function target attributes in limcode_ffi.cpp) and selected at
+    // process startup by `limcode_select_simd_level`, called once from Rust's
+    // `selected_simd_level()` using `is_x86_feature_detected!`.
 
     // On macOS, disable parallel algorithms if not supported
     if target_os == "macos" {
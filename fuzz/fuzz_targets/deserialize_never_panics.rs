@@ -0,0 +1,44 @@
+//! Feeds arbitrary raw byte buffers into `deserialize`/`deserialize_pod` for a few representative
+//! target types and checks that a hostile or truncated buffer only ever produces `Ok` or a
+//! structured `Err` - never a panic, an unbounded allocation, or an out-of-bounds read.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NestedStruct {
+    value: i32,
+    flag: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum TestEnum {
+    Unit,
+    NewType(u64),
+    Tuple(u32, u32),
+    Struct { x: i16, y: i16 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct FuzzTarget {
+    id: u64,
+    name: String,
+    data: Vec<u8>,
+    values: Vec<u32>,
+    nested: Option<NestedStruct>,
+    tag: TestEnum,
+}
+
+// A generous but finite ceiling keeps a hostile length prefix from driving the decoder to try to
+// allocate gigabytes on bytes that will fail to fully populate anyway - same budget the hand
+// written `deserialize_with_limit` tests exercise.
+const MAX_LEN: u64 = 1 << 20;
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = limcode::deserialize::<FuzzTarget>(bytes);
+    let _ = limcode::deserialize_with_limit::<FuzzTarget>(bytes, MAX_LEN);
+    let _ = limcode::deserialize_pod::<u32>(bytes);
+    let _ = limcode::deserialize_pod::<u64>(bytes);
+});
@@ -0,0 +1,61 @@
+//! Generates random instances of a representative type, round-trips each through limcode, and
+//! cross-checks the serialized bytes against bincode and wincode byte-for-byte.
+//!
+//! This is the same cross-compat assertion `tests/full_compat.rs` and `tests/validate_wincode.rs`
+//! make by hand for a handful of values, run here over whatever `arbitrary` generates from the
+//! fuzzer's corpus - catching cross-compat regressions those fixed cases can't.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Arbitrary)]
+struct NestedStruct {
+    value: i32,
+    flag: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Arbitrary)]
+enum TestEnum {
+    Unit,
+    NewType(u64),
+    Tuple(u32, u32),
+    Struct { x: i16, y: i16 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Arbitrary)]
+struct FuzzTarget {
+    id: u64,
+    name: String,
+    data: Vec<u8>,
+    values: Vec<u32>,
+    nested: Option<NestedStruct>,
+    tag: TestEnum,
+}
+
+fuzz_target!(|value: FuzzTarget| {
+    let limcode_bytes = limcode::serialize(&value).expect("limcode serialize must not fail");
+    let bincode_bytes = bincode::serialize(&value).expect("bincode serialize must not fail");
+    assert_eq!(
+        limcode_bytes, bincode_bytes,
+        "limcode and bincode must agree byte-for-byte on {:?}",
+        value
+    );
+
+    let decoded: FuzzTarget =
+        limcode::deserialize(&limcode_bytes).expect("limcode deserialize must not fail");
+    assert_eq!(decoded, value, "round trip through limcode must be lossless");
+
+    // wincode only speaks POD element types directly; exercise it against the Vec<u32> field
+    // the same way `tests/output_validation.rs` does for the full-crate three-way check.
+    let wincode_bytes = wincode::serialize(&value.values).expect("wincode serialize must not fail");
+    let limcode_pod_bytes =
+        limcode::serialize_pod(&value.values).expect("limcode serialize_pod must not fail");
+    assert_eq!(
+        wincode_bytes, limcode_pod_bytes,
+        "limcode and wincode must agree byte-for-byte on Vec<u32> {:?}",
+        value.values
+    );
+});
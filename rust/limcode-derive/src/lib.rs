@@ -0,0 +1,232 @@
+//! `#[derive(Encode, Decode)]` for limcode's structured `Encode`/`Decode` traits (see
+//! `limcode::codec`), and `#[derive(ColumnarPod)]` for `limcode::bitpack`'s columnar bit-packing
+//!
+//! Struct fields are visited in declaration order, emitting one `Encode::encode`/`Decode::decode`
+//! call per field. Enums are encoded as a LEB128 discriminant (the variant's declaration index,
+//! via `Encoder::write_varint`/`Decoder::read_varint_fast`) followed by the selected variant's
+//! fields, encoded the same way. `write_varint` (not `write_varint_fast`) is used deliberately
+//! here - see the note in `limcode::codec` about the fast path's fast_buffer/FFI ordering hazard.
+//!
+//! `#[derive(ColumnarPod)]` instead implements `limcode::bitpack::ColumnarPod` for a named-field
+//! struct whose fields are all `i8`/`i16`/`i32`/`i64`/`u8`/`u16`/`u32`/`u64`/`bool`, generating a
+//! `get`/`set` accessor pair per field so `serialize_pod_struct_columnar`/
+//! `deserialize_pod_struct_columnar` can transpose a `&[Self]` without a hand-written closure per
+//! field. Any other field type is rejected at compile time.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index};
+
+#[proc_macro_derive(Encode)]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => encode_struct_fields(&data.fields),
+        Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let variant_name = &variant.ident;
+                let index = index as u64;
+                match &variant.fields {
+                    Fields::Unit => quote! {
+                        Self::#variant_name => {
+                            enc.write_varint(#index);
+                        }
+                    },
+                    Fields::Unnamed(fields) => {
+                        let bindings: Vec<_> = (0..fields.unnamed.len())
+                            .map(|i| quote::format_ident!("field_{}", i))
+                            .collect();
+                        let encodes = bindings
+                            .iter()
+                            .map(|b| quote! { limcode::codec::Encode::encode(#b, enc); });
+                        quote! {
+                            Self::#variant_name(#(#bindings),*) => {
+                                enc.write_varint(#index);
+                                #(#encodes)*
+                            }
+                        }
+                    }
+                    Fields::Named(fields) => {
+                        let names: Vec<_> = fields
+                            .named
+                            .iter()
+                            .map(|f| f.ident.clone().unwrap())
+                            .collect();
+                        let encodes = names
+                            .iter()
+                            .map(|n| quote! { limcode::codec::Encode::encode(#n, enc); });
+                        quote! {
+                            Self::#variant_name { #(#names),* } => {
+                                enc.write_varint(#index);
+                                #(#encodes)*
+                            }
+                        }
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => panic!("#[derive(Encode)] does not support unions"),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics limcode::codec::Encode for #name #ty_generics #where_clause {
+            fn encode(&self, enc: &mut limcode::Encoder) {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+fn encode_struct_fields(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let calls = fields.named.iter().map(|f| {
+                let name = f.ident.as_ref().unwrap();
+                quote! { limcode::codec::Encode::encode(&self.#name, enc); }
+            });
+            quote! { #(#calls)* }
+        }
+        Fields::Unnamed(fields) => {
+            let calls = fields.unnamed.iter().enumerate().map(|(i, _)| {
+                let index = Index::from(i);
+                quote! { limcode::codec::Encode::encode(&self.#index, enc); }
+            });
+            quote! { #(#calls)* }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+#[proc_macro_derive(Decode)]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let construct = decode_fields(&data.fields);
+            quote! { Ok(Self #construct) }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().enumerate().map(|(index, variant)| {
+                let variant_name = &variant.ident;
+                let index = index as u64;
+                let construct = decode_fields(&variant.fields);
+                quote! { #index => Ok(Self::#variant_name #construct), }
+            });
+            quote! {
+                let discriminant = dec.read_varint_fast()?;
+                match discriminant {
+                    #(#arms)*
+                    _ => Err("unknown enum discriminant"),
+                }
+            }
+        }
+        Data::Union(_) => panic!("#[derive(Decode)] does not support unions"),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics limcode::codec::Decode for #name #ty_generics #where_clause {
+            fn decode(dec: &mut limcode::Decoder) -> Result<Self, &'static str> {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+fn decode_fields(fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let inits = fields.named.iter().map(|f| {
+                let name = f.ident.as_ref().unwrap();
+                let ty = &f.ty;
+                quote! { #name: <#ty as limcode::codec::Decode>::decode(dec)? }
+            });
+            quote! { { #(#inits),* } }
+        }
+        Fields::Unnamed(fields) => {
+            let inits = fields.unnamed.iter().map(|f| {
+                let ty = &f.ty;
+                quote! { <#ty as limcode::codec::Decode>::decode(dec)? }
+            });
+            quote! { ( #(#inits),* ) }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+enum ColumnarFieldKind {
+    Signed,
+    Unsigned,
+    Bool,
+}
+
+fn columnar_field_kind(ty: &syn::Type) -> ColumnarFieldKind {
+    let ident = match ty {
+        syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+        _ => None,
+    };
+    match ident.as_deref() {
+        Some("i8") | Some("i16") | Some("i32") | Some("i64") => ColumnarFieldKind::Signed,
+        Some("u8") | Some("u16") | Some("u32") | Some("u64") => ColumnarFieldKind::Unsigned,
+        Some("bool") => ColumnarFieldKind::Bool,
+        _ => panic!(
+            "#[derive(ColumnarPod)] only supports i8/i16/i32/i64/u8/u16/u32/u64/bool fields"
+        ),
+    }
+}
+
+#[proc_macro_derive(ColumnarPod)]
+pub fn derive_columnar_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => panic!("#[derive(ColumnarPod)] requires a struct with named fields"),
+        },
+        _ => panic!("#[derive(ColumnarPod)] only supports structs with named fields"),
+    };
+
+    let entries = named.iter().map(|f| {
+        let field_name = f.ident.as_ref().unwrap();
+        let field_name_str = field_name.to_string();
+        let ty = &f.ty;
+        let kind = columnar_field_kind(ty);
+        let signed = matches!(kind, ColumnarFieldKind::Signed);
+        let set_stmt = match kind {
+            ColumnarFieldKind::Bool => quote! { s.#field_name = v != 0; },
+            _ => quote! { s.#field_name = v as #ty; },
+        };
+        quote! {
+            limcode::bitpack::ColumnarField {
+                name: #field_name_str,
+                signed: #signed,
+                get: |s: &Self| s.#field_name as i64,
+                set: |s: &mut Self, v: i64| { #set_stmt },
+            }
+        }
+    });
+
+    let expanded = quote! {
+        impl #impl_generics limcode::bitpack::ColumnarPod for #name #ty_generics #where_clause {
+            const FIELDS: &'static [limcode::bitpack::ColumnarField<Self>] = &[
+                #(#entries),*
+            ];
+        }
+    };
+    expanded.into()
+}
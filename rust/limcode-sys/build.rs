@@ -4,7 +4,6 @@ fn main() {
     // Compile C++ library - remove LTO flag which causes issues
     let mut build = cc::Build::new();
 
-    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
     let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
 
     build
@@ -14,28 +13,12 @@ fn main() {
         .opt_level(3)
         .flag_if_supported("-std=c++20");
 
-    // Only apply x86_64 SIMD flags on x86_64 architecture
-    if target_arch == "x86_64" {
-        // In CI, use conservative baseline to avoid SIGILL on different runners
-        // In local builds, use -march=native for maximum performance
-        let is_ci = env::var("CI").is_ok() || env::var("GITHUB_ACTIONS").is_ok();
-
-        if is_ci {
-            // x86-64-v2: SSE4.2, POPCNT, SSSE3 (available on all modern CI runners)
-            build.flag_if_supported("-march=x86-64-v2");
-        } else {
-            // Local builds: optimize for the actual CPU
-            build
-                .flag_if_supported("-march=native")
-                .flag_if_supported("-mavx512f")
-                .flag_if_supported("-mavx512bw")
-                .flag_if_supported("-mavx512dq")
-                .flag_if_supported("-mavx512vl")
-                .flag_if_supported("-mavx2")
-                .flag_if_supported("-msse4.2")
-                .flag_if_supported("-mbmi2");
-        }
-    }
+    // No `-march`/`-mavx*` flags here, on purpose: the scalar/SSE4.2/AVX2/AVX-512 variants of the
+    // bulk-copy routines are compiled in unconditionally (via per-function target attributes in
+    // limcode_ffi.cpp) and selected at process startup by `limcode_select_simd_level`, called
+    // once from Rust's `selected_simd_level()` using `is_x86_feature_detected!`, instead of being
+    // baked into the whole translation unit at compile time. See the root crate's `build.rs` for
+    // the full rationale.
 
     // On macOS, disable parallel algorithms if not supported
     if target_os == "macos" {
@@ -29,6 +29,7 @@ extern "C" {
     pub fn limcode_encoder_size(encoder: *const LimcodeEncoder) -> usize;
     pub fn limcode_encoder_data(encoder: *const LimcodeEncoder) -> *const u8;
     pub fn limcode_encoder_into_vec(encoder: *mut LimcodeEncoder, out_size: *mut usize) -> *mut u8;
+    pub fn limcode_encoder_clear(encoder: *mut LimcodeEncoder);
 
     // ==================== Decoder API ====================
     pub fn limcode_decoder_new(data: *const u8, len: usize) -> *mut LimcodeDecoder;
@@ -49,4 +50,10 @@ extern "C" {
     pub fn limcode_encoder_buffer_ptr(encoder: *mut LimcodeEncoder) -> *mut u8;
     pub fn limcode_encoder_advance(encoder: *mut LimcodeEncoder, bytes: usize);
     pub fn limcode_encoder_alloc_space(encoder: *mut LimcodeEncoder, bytes: usize, out_offset: *mut usize) -> *mut u8;
+
+    // ==================== Runtime SIMD Dispatch ====================
+    // Selects which compiled-in bulk-copy variant (scalar/SSE4.2/AVX2/AVX-512) `limcode_encoder_write_bytes`/
+    // `limcode_decoder_read_bytes` use from now on. Call once per process with the widest level
+    // `is_x86_feature_detected!` confirms the host CPU supports.
+    pub fn limcode_select_simd_level(level: c_int) -> c_int;
 }
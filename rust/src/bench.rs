@@ -0,0 +1,300 @@
+//! Built-in round-trip throughput benchmarking harness
+//!
+//! The benchmarks under `benches/` and `examples/` each hand-roll the same latency
+//! percentile (min/median/p95/p99) and GB/s throughput measurement inline in `main`. This
+//! module promotes that loop into a reusable API so downstream users can regression-test
+//! limcode's performance in their own CI instead of copy-pasting it.
+
+use std::time::{Duration, Instant};
+
+/// Latency percentiles and throughput for a single benchmarked operation
+#[derive(Debug, Clone, Copy)]
+pub struct BenchResult {
+    pub iterations: usize,
+    pub bytes_per_iteration: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+    /// Average throughput across all samples, in GB/s (bytes/ns)
+    pub throughput_gbps: f64,
+}
+
+impl BenchResult {
+    fn from_samples(mut samples: Vec<Duration>, bytes_per_iteration: usize) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let percentile = |p: f64| samples[(((n - 1) as f64) * p).round() as usize];
+
+        let avg_ns = samples.iter().map(|d| d.as_nanos()).sum::<u128>() as f64 / n as f64;
+        let throughput_gbps = if avg_ns > 0.0 {
+            bytes_per_iteration as f64 / avg_ns
+        } else {
+            0.0
+        };
+
+        BenchResult {
+            iterations: n,
+            bytes_per_iteration,
+            min: samples[0],
+            median: percentile(0.50),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+            max: samples[n - 1],
+            throughput_gbps,
+        }
+    }
+}
+
+/// Machine context captured alongside results so numbers are comparable across hosts
+#[derive(Debug, Clone)]
+pub struct MachineInfo {
+    pub cpu_cores: usize,
+    pub target_arch: &'static str,
+}
+
+impl MachineInfo {
+    /// Probe the current host's core count and target architecture
+    pub fn current() -> Self {
+        MachineInfo {
+            cpu_cores: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            target_arch: std::env::consts::ARCH,
+        }
+    }
+}
+
+/// Run `op` `iterations` times, recording per-call latency, and return percentile/throughput stats
+///
+/// `bytes_per_iteration` is the payload size processed by a single call to `op`, used to
+/// compute `throughput_gbps`.
+pub fn benchmark_operation<F: FnMut()>(
+    iterations: usize,
+    bytes_per_iteration: usize,
+    mut op: F,
+) -> BenchResult {
+    assert!(iterations > 0, "benchmark_operation requires iterations > 0");
+
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        op();
+        samples.push(start.elapsed());
+    }
+
+    BenchResult::from_samples(samples, bytes_per_iteration)
+}
+
+/// One entry in a `throughput_sweep` size table
+pub struct SweepConfig {
+    pub num_elements: usize,
+    pub label: &'static str,
+    pub iterations: usize,
+}
+
+/// Serialize and deserialize results for one `SweepConfig` entry
+pub struct SweepResult {
+    pub label: &'static str,
+    pub serialize: BenchResult,
+    pub deserialize: BenchResult,
+}
+
+/// Run serialize+deserialize over a configurable size table, returning structured results
+///
+/// `make_data` builds the input for a given element count, `serialize`/`deserialize` are the
+/// operations under test. Unlike the ad-hoc benchmark loops this replaces, results are
+/// returned as data rather than printed, so callers can assert on them or format them
+/// however their CI requires.
+pub fn throughput_sweep<T, S, D>(
+    configs: &[SweepConfig],
+    make_data: impl Fn(usize) -> Vec<T>,
+    mut serialize: S,
+    mut deserialize: D,
+) -> Vec<SweepResult>
+where
+    S: FnMut(&[T]) -> Vec<u8>,
+    D: FnMut(&[u8]),
+{
+    configs
+        .iter()
+        .map(|config| {
+            let data = make_data(config.num_elements);
+            let bytes_per_iteration = std::mem::size_of::<T>() * data.len();
+
+            let mut encoded = Vec::new();
+            let ser_result = benchmark_operation(config.iterations, bytes_per_iteration, || {
+                encoded = serialize(&data);
+            });
+
+            let deser_result = benchmark_operation(config.iterations, bytes_per_iteration, || {
+                deserialize(&encoded);
+            });
+
+            SweepResult {
+                label: config.label,
+                serialize: ser_result,
+                deserialize: deser_result,
+            }
+        })
+        .collect()
+}
+
+/// Scalar-vs-SIMD result pair for one payload size in `bulk_copy_comparison`
+#[cfg(feature = "simd")]
+pub struct BulkCopyComparison {
+    pub label: &'static str,
+    pub scalar: BenchResult,
+    pub simd: BenchResult,
+}
+
+/// Compare `std::ptr::copy_nonoverlapping` against `simd::bulk_copy` at 1KB/64KB/1MB
+///
+/// The `<=64KB` branch of `serialize_pod_into` switches between these two copy strategies
+/// depending on whether the `simd` feature is enabled; this quantifies the gain at the sizes
+/// that matter for that decision instead of leaving it to estimation.
+#[cfg(feature = "simd")]
+pub fn bulk_copy_comparison(iterations: usize) -> Vec<BulkCopyComparison> {
+    let sizes: &[(&str, usize)] = &[("1KB", 1024), ("64KB", 65536), ("1MB", 1_048_576)];
+
+    sizes
+        .iter()
+        .map(|&(label, len)| {
+            let src = vec![0xABu8; len];
+            let mut dst = vec![0u8; len];
+
+            let scalar = benchmark_operation(iterations, len, || unsafe {
+                std::ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len);
+            });
+
+            let simd = benchmark_operation(iterations, len, || unsafe {
+                crate::simd::bulk_copy(dst.as_mut_ptr(), src.as_ptr(), len);
+            });
+
+            BulkCopyComparison { label, scalar, simd }
+        })
+        .collect()
+}
+
+/// Scalar-vs-SIMD result for one payload size in `nt_memcpy_comparison`, tagged with the tier
+/// `serializer::fast_nt_memcpy` actually dispatched to on this host
+pub struct NtMemcpyComparison {
+    pub label: &'static str,
+    pub tier: &'static str,
+    pub scalar: BenchResult,
+    pub simd: BenchResult,
+}
+
+/// Compare `std::ptr::copy_nonoverlapping` against `serializer::fast_nt_memcpy` at 128KB/1MB/16MB
+///
+/// `fast_nt_memcpy`'s large-payload branch in `serialize_pod_into`/`serialize_bincode` dispatches
+/// to the best SIMD tier at runtime; `tier` on each result reports which kernel
+/// (`serializer::cpu_capabilities().nt_memcpy_tier()`) actually ran on this host, so a bench run
+/// makes clear whether AVX-512, AVX2, or the scalar fallback was measured.
+pub fn nt_memcpy_comparison(iterations: usize) -> Vec<NtMemcpyComparison> {
+    let sizes: &[(&str, usize)] = &[("128KB", 131_072), ("1MB", 1_048_576), ("16MB", 16_777_216)];
+    let tier = crate::serializer::cpu_capabilities().nt_memcpy_tier();
+
+    sizes
+        .iter()
+        .map(|&(label, len)| {
+            let src = vec![0xABu8; len];
+            let mut dst = vec![0u8; len];
+
+            let scalar = benchmark_operation(iterations, len, || unsafe {
+                std::ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len);
+            });
+
+            let simd = benchmark_operation(iterations, len, || unsafe {
+                crate::serializer::fast_nt_memcpy(dst.as_mut_ptr(), src.as_ptr(), len);
+            });
+
+            NtMemcpyComparison { label, tier, scalar, simd }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_operation_reports_iterations() {
+        let result = benchmark_operation(10, 1024, || {
+            std::hint::black_box(1 + 1);
+        });
+        assert_eq!(result.iterations, 10);
+        assert!(result.min <= result.median);
+        assert!(result.median <= result.max);
+    }
+
+    #[test]
+    fn test_throughput_sweep_round_trips() {
+        use crate::{deserialize_bincode, serialize_bincode};
+
+        let configs = vec![
+            SweepConfig {
+                num_elements: 8,
+                label: "64B",
+                iterations: 5,
+            },
+            SweepConfig {
+                num_elements: 128,
+                label: "1KB",
+                iterations: 5,
+            },
+        ];
+
+        let results = throughput_sweep(
+            &configs,
+            |n| (0..n).map(|i| i as u64).collect::<Vec<u64>>(),
+            |data: &[u64]| {
+                let bytes = unsafe {
+                    std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+                };
+                serialize_bincode(bytes)
+            },
+            |bytes| {
+                deserialize_bincode(bytes).unwrap();
+            },
+        );
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].label, "64B");
+        assert_eq!(results[0].serialize.iterations, 5);
+    }
+
+    #[test]
+    fn test_machine_info_has_at_least_one_core() {
+        assert!(MachineInfo::current().cpu_cores >= 1);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_bulk_copy_comparison_covers_the_three_requested_sizes() {
+        let results = bulk_copy_comparison(3);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].label, "1KB");
+        assert_eq!(results[1].label, "64KB");
+        assert_eq!(results[2].label, "1MB");
+        for r in &results {
+            assert_eq!(r.scalar.iterations, 3);
+            assert_eq!(r.simd.iterations, 3);
+        }
+    }
+
+    #[test]
+    fn test_nt_memcpy_comparison_covers_the_three_requested_sizes_and_tags_a_tier() {
+        let results = nt_memcpy_comparison(3);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].label, "128KB");
+        assert_eq!(results[1].label, "1MB");
+        assert_eq!(results[2].label, "16MB");
+        for r in &results {
+            assert!(["avx512", "avx2", "scalar"].contains(&r.tier));
+            assert_eq!(r.scalar.iterations, 3);
+            assert_eq!(r.simd.iterations, 3);
+        }
+    }
+}
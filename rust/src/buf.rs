@@ -0,0 +1,146 @@
+//! A `bytes::Buf`-style borrowing cursor for walking framed records out of one buffer
+//!
+//! [`crate::serialize_bincode`]/[`crate::deserialize_bincode`] round-trip a single u64-LE-length-
+//! prefixed frame, but have nothing to say about a buffer that packs several such frames back to
+//! back - decoding the second one means re-deriving "skip 8 + the first frame's length" by hand
+//! at the call site. [`LimReader`] generalizes that into a stateful cursor, modeled on the
+//! `bytes` crate's `Buf` trait: each `get_*` call both returns a value and advances the cursor
+//! past it, so [`LimReader::get_frame`] can be called in a loop to decode every frame in a
+//! concatenated buffer with no copying and no manual offset bookkeeping.
+
+/// Error type for [`LimReader`] and [`deserialize`]/[`deserialize_borrowed`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// Fewer bytes remained in the buffer than the read required
+    Eof,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Eof => write!(f, "unexpected end of buffer"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A cursor over a borrowed byte slice, handing back sub-slices that stay tied to the original
+/// buffer's lifetime rather than copying out of it
+pub struct LimReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> LimReader<'a> {
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        LimReader { buf, pos: 0 }
+    }
+
+    /// Bytes not yet consumed by a `get_*` call
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Read a little-endian `u64`, advancing the cursor past it
+    #[inline]
+    pub fn get_u64_le(&mut self) -> Result<u64, Error> {
+        let bytes = self.get_slice(8)?;
+        let mut array = [0u8; 8];
+        array.copy_from_slice(bytes);
+        Ok(u64::from_le_bytes(array))
+    }
+
+    /// Borrow the next `len` bytes, advancing the cursor past them
+    #[inline]
+    pub fn get_slice(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < len {
+            return Err(Error::Eof);
+        }
+        let start = self.pos;
+        self.pos += len;
+        Ok(&self.buf[start..self.pos])
+    }
+
+    /// Read one `serialize_bincode`-framed record - an 8-byte little-endian length followed by
+    /// that many bytes of payload - advancing the cursor past the whole frame
+    ///
+    /// Calling this in a loop while `remaining() > 0` decodes every frame in a buffer that
+    /// packs several records back to back.
+    #[inline]
+    pub fn get_frame(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.get_u64_le()? as usize;
+        self.get_slice(len)
+    }
+}
+
+/// Read a single `u64`-LE-length-prefixed frame from the start of `data`, returning a zero-copy
+/// view into it
+///
+/// Equivalent to [`crate::deserialize_bincode`], implemented on top of [`LimReader`] instead of
+/// raw pointer arithmetic - use this when you also want [`LimReader`]'s chained-frame support
+/// available at the same call site, or [`crate::deserialize_bincode`] directly otherwise.
+pub fn deserialize(data: &[u8]) -> Result<&[u8], Error> {
+    LimReader::new(data).get_frame()
+}
+
+/// Alias for [`deserialize`], named to match the borrowed-slice terminology used by `LimReader`
+/// and the `bytes` crate it's modeled after
+#[inline]
+pub fn deserialize_borrowed(data: &[u8]) -> Result<&[u8], Error> {
+    deserialize(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialize_bincode;
+
+    #[test]
+    fn test_deserialize_round_trips_a_single_frame() {
+        let encoded = serialize_bincode(b"hello");
+        assert_eq!(deserialize(&encoded).unwrap(), b"hello");
+        assert_eq!(deserialize_borrowed(&encoded).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_deserialize_rejects_truncated_length_header() {
+        assert_eq!(deserialize(&[1, 2, 3]).unwrap_err(), Error::Eof);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_truncated_payload() {
+        let mut encoded = serialize_bincode(b"hello");
+        encoded.truncate(encoded.len() - 1);
+        assert_eq!(deserialize(&encoded).unwrap_err(), Error::Eof);
+    }
+
+    #[test]
+    fn test_lim_reader_chains_multiple_frames_out_of_one_buffer() {
+        let mut buf = serialize_bincode(b"first");
+        buf.extend_from_slice(&serialize_bincode(b"second"));
+        buf.extend_from_slice(&serialize_bincode(b""));
+
+        let mut reader = LimReader::new(&buf);
+        assert_eq!(reader.get_frame().unwrap(), b"first");
+        assert_eq!(reader.get_frame().unwrap(), b"second");
+        assert_eq!(reader.get_frame().unwrap(), b"");
+        assert_eq!(reader.remaining(), 0);
+        assert_eq!(reader.get_frame().unwrap_err(), Error::Eof);
+    }
+
+    #[test]
+    fn test_lim_reader_get_u64_le_and_get_slice_advance_the_cursor() {
+        let mut buf = 42u64.to_le_bytes().to_vec();
+        buf.extend_from_slice(b"abc");
+
+        let mut reader = LimReader::new(&buf);
+        assert_eq!(reader.remaining(), 11);
+        assert_eq!(reader.get_u64_le().unwrap(), 42);
+        assert_eq!(reader.remaining(), 3);
+        assert_eq!(reader.get_slice(3).unwrap(), b"abc");
+        assert_eq!(reader.remaining(), 0);
+    }
+}
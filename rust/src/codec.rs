@@ -0,0 +1,264 @@
+//! Trait-based `Encode`/`Decode` structured (de)serialization
+//!
+//! The rest of the crate only offers hand-rolled `write_u*`/`read_u*` calls on `Encoder`/
+//! `Decoder`, which forces callers to keep the encode and decode sides of a struct in sync by
+//! hand. `Encode`/`Decode` give every primitive, collection, and (via `#[derive(Encode,
+//! Decode)]` in the `limcode-derive` crate) user-defined struct/enum a single symmetric
+//! implementation, while still bottoming out on the existing `Encoder`/`Decoder` methods for the
+//! actual bytes on the wire.
+//!
+//! Collection/string lengths are written via `Encoder::write_varint` rather than the newer
+//! `write_varint_fast`: the latter appends to `Encoder`'s `fast_buffer` instead of the C++
+//! encoder, and since `finish()` always flushes `fast_buffer` *after* anything already written
+//! through the FFI path (as every other `Encode` impl here does via `write_u8`/`write_u32`/etc),
+//! interleaving the two would reorder bytes on the wire. Decoding still uses the newer
+//! `read_varint_fast`, which has no such ordering hazard - it parses directly from the
+//! original input slice regardless of which path wrote it.
+
+use crate::{Decoder, Encoder};
+
+/// Types that can encode themselves into an `Encoder`
+pub trait Encode {
+    fn encode(&self, enc: &mut Encoder);
+}
+
+/// Types that can decode themselves from a `Decoder`
+pub trait Decode: Sized {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str>;
+}
+
+/// Encode `value` into a freshly allocated buffer
+pub fn encode<T: Encode>(value: &T) -> Vec<u8> {
+    let mut enc = Encoder::new();
+    value.encode(&mut enc);
+    enc.finish()
+}
+
+/// Decode a `T` from `bytes`
+pub fn decode<T: Decode>(bytes: &[u8]) -> Result<T, &'static str> {
+    let mut dec = Decoder::new(bytes);
+    T::decode(&mut dec)
+}
+
+macro_rules! impl_codec_int {
+    ($ty:ty, $write:ident, $read:ident) => {
+        impl Encode for $ty {
+            fn encode(&self, enc: &mut Encoder) {
+                enc.$write(*self);
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+                Ok(dec.$read()?)
+            }
+        }
+    };
+}
+
+impl_codec_int!(u8, write_u8, read_u8);
+impl_codec_int!(u16, write_u16, read_u16);
+impl_codec_int!(u32, write_u32, read_u32);
+impl_codec_int!(u64, write_u64, read_u64);
+
+// f32/f64 use the compact tagged encoding (`write_f32_compact`/`write_f64_compact`) rather than
+// the raw bit-pattern writes, so arrays/vecs of mostly-zero or integer-valued floats (weights,
+// probabilities, coordinates) get the space savings automatically; adversarial/full-precision
+// values still round-trip exactly via that encoding's raw-bits fallback case.
+impl_codec_int!(f32, write_f32_compact, read_f32_compact);
+impl_codec_int!(f64, write_f64_compact, read_f64_compact);
+
+impl Encode for bool {
+    fn encode(&self, enc: &mut Encoder) {
+        enc.write_u8(*self as u8);
+    }
+}
+
+impl Decode for bool {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+        match dec.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err("invalid bool tag"),
+        }
+    }
+}
+
+impl<T: Encode> Encode for Vec<T> {
+    fn encode(&self, enc: &mut Encoder) {
+        enc.write_varint(self.len() as u64);
+        for item in self {
+            item.encode(enc);
+        }
+    }
+}
+
+impl<T: Decode> Decode for Vec<T> {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+        let len = dec.read_varint_fast()? as usize;
+        // Cap the eager reservation so a hostile/corrupt length prefix can't force a huge
+        // up-front allocation before any data has actually been validated.
+        let mut out = Vec::with_capacity(len.min(4096));
+        for _ in 0..len {
+            out.push(T::decode(dec)?);
+        }
+        Ok(out)
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, enc: &mut Encoder) {
+        enc.write_varint(self.len() as u64);
+        enc.write_bytes(self.as_bytes());
+    }
+}
+
+impl Decode for String {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+        let len = dec.read_varint_fast()? as usize;
+        let mut buf = vec![0u8; len];
+        dec.read_bytes(&mut buf)?;
+        String::from_utf8(buf).map_err(|_| "invalid UTF-8 in decoded string")
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode(&self, enc: &mut Encoder) {
+        match self {
+            Some(value) => {
+                enc.write_u8(1);
+                value.encode(enc);
+            }
+            None => enc.write_u8(0),
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+        match dec.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(T::decode(dec)?)),
+            _ => Err("invalid Option tag"),
+        }
+    }
+}
+
+macro_rules! impl_codec_tuple {
+    ($($idx:tt => $name:ident),+) => {
+        impl<$($name: Encode),+> Encode for ($($name,)+) {
+            fn encode(&self, enc: &mut Encoder) {
+                $(self.$idx.encode(enc);)+
+            }
+        }
+
+        impl<$($name: Decode),+> Decode for ($($name,)+) {
+            fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+                Ok(($($name::decode(dec)?,)+))
+            }
+        }
+    };
+}
+
+impl_codec_tuple!(0 => A);
+impl_codec_tuple!(0 => A, 1 => B);
+impl_codec_tuple!(0 => A, 1 => B, 2 => C);
+impl_codec_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode(&self, enc: &mut Encoder) {
+        for item in self {
+            item.encode(enc);
+        }
+    }
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode(dec: &mut Decoder) -> Result<Self, &'static str> {
+        // `MaybeUninit` avoids requiring `T: Default`; if any element fails to decode we must
+        // drop only the elements already written before bailing out.
+        use std::mem::MaybeUninit;
+
+        let mut out: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        for (i, slot) in out.iter_mut().enumerate() {
+            match T::decode(dec) {
+                Ok(value) => {
+                    slot.write(value);
+                }
+                Err(e) => {
+                    for initialized in &mut out[..i] {
+                        unsafe {
+                            initialized.assume_init_drop();
+                        }
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(unsafe { (&out as *const _ as *const [T; N]).read() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives_round_trip() {
+        assert_eq!(decode::<u8>(&encode(&7u8)).unwrap(), 7u8);
+        assert_eq!(decode::<u32>(&encode(&123456u32)).unwrap(), 123456u32);
+        assert!(decode::<bool>(&encode(&true)).unwrap());
+        assert!(!decode::<bool>(&encode(&false)).unwrap());
+    }
+
+    #[test]
+    fn test_float_round_trip_uses_compact_encoding() {
+        // Compact tags: 0.0 is the zero tag alone; 1.0 is the integer tag plus zigzag varint 2.
+        assert_eq!(encode(&0.0f32), vec![0]);
+        assert_eq!(encode(&1.0f32), vec![1, 2]);
+        assert_eq!(decode::<f32>(&encode(&1.0f32)).unwrap(), 1.0f32);
+        assert_eq!(decode::<f64>(&encode(&-3.5f64)).unwrap(), -3.5f64);
+
+        let values: Vec<f32> = vec![0.0, 1.0, -1.0, 2.5, 16_777_216.0];
+        assert_eq!(decode::<Vec<f32>>(&encode(&values)).unwrap(), values);
+    }
+
+    #[test]
+    fn test_vec_and_string_round_trip() {
+        let values: Vec<u32> = vec![1, 2, 3, 4, 5];
+        assert_eq!(decode::<Vec<u32>>(&encode(&values)).unwrap(), values);
+
+        let text = String::from("hello limcode");
+        assert_eq!(decode::<String>(&encode(&text)).unwrap(), text);
+    }
+
+    #[test]
+    fn test_option_round_trips() {
+        let some_value: Option<u64> = Some(42);
+        let none_value: Option<u64> = None;
+        assert_eq!(decode::<Option<u64>>(&encode(&some_value)).unwrap(), some_value);
+        assert_eq!(decode::<Option<u64>>(&encode(&none_value)).unwrap(), none_value);
+    }
+
+    #[test]
+    fn test_tuple_round_trips() {
+        let value = (1u8, 2u32, String::from("three"));
+        assert_eq!(
+            decode::<(u8, u32, String)>(&encode(&value)).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_fixed_array_round_trips() {
+        let value: [u32; 4] = [10, 20, 30, 40];
+        assert_eq!(decode::<[u32; 4]>(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn test_nested_vec_of_struct_like_tuples_round_trips() {
+        let value: Vec<(u8, String)> = vec![(1, "a".into()), (2, "bb".into()), (3, "ccc".into())];
+        assert_eq!(decode::<Vec<(u8, String)>>(&encode(&value)).unwrap(), value);
+    }
+}
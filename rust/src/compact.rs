@@ -0,0 +1,994 @@
+//! Bit-packed serde `Serializer`/`Deserializer` pair backed by `bitpack::BitEncoder`/`BitDecoder`
+//!
+//! Where `serializer`/`deserializer` are byte-aligned (every field costs at least one whole
+//! byte), this mode packs bools into single bits and gamma-codes integers, lengths, and enum
+//! variant indices - a large win for structs dominated by bools, small enums, and small-valued
+//! integers, at the cost of being slower to encode/decode than the byte-aligned path. Signed
+//! integers are zigzag-mapped to unsigned before gamma-coding (same mapping as
+//! `Encoder::write_svarint`), since gamma coding only handles non-negative values. Floats are
+//! written as their full-width bit pattern - the vast majority of float values don't compress
+//! under gamma coding, so there's nothing to gain by trying.
+//!
+//! Sequences, maps, and strings/bytes are length-prefixed with `write_gamma` rather than a fixed
+//! 8-byte length, since most collections in practice are small. Struct and tuple fields have no
+//! length prefix at all (the field count is already known to both sides from the type), matching
+//! `serializer`/`deserializer`'s own convention.
+//!
+//! Integer encoding is controlled by [`CompactConfig`]: the default `IntEncoding::Varint` is the
+//! gamma coding described above, while `IntEncoding::Fixint` writes every integer as its raw
+//! fixed-width bit pattern instead (still bit-packed, just not gamma-coded) - useful for measuring
+//! how much of this mode's size win actually comes from gamma coding versus from bit-packing bools
+//! and omitting byte-alignment padding.
+
+use serde::de::{self, Deserialize, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::{ser, Serialize};
+
+use crate::bitpack::{BitDecoder, BitEncoder};
+use crate::deserializer::IntEncoding;
+
+/// Configuration for `serialize_compact_with_config`/`deserialize_compact_with_config`
+///
+/// `int_encoding` defaults to `IntEncoding::Varint` (this module's usual gamma coding) rather
+/// than `IntEncoding::Fixint`'s own default, since gamma coding is what `serialize_compact` has
+/// always used - `CompactConfig::default()` reproduces today's behavior exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactConfig {
+    pub int_encoding: IntEncoding,
+}
+
+impl Default for CompactConfig {
+    fn default() -> Self {
+        Self { int_encoding: IntEncoding::Varint }
+    }
+}
+
+/// Error type for `serialize_compact`/`deserialize_compact`
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    /// A `BitDecoder` read ran past the end of the packed stream
+    BitStream(&'static str),
+    /// The 8-byte bit-length header at the start of a `serialize_compact` payload was missing
+    Truncated,
+    InvalidBool(u64),
+    InvalidChar,
+    Utf8Error(std::str::Utf8Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Message(msg) => write!(f, "{}", msg),
+            Error::BitStream(msg) => write!(f, "{}", msg),
+            Error::Truncated => write!(f, "truncated compact payload: missing bit-length header"),
+            Error::InvalidBool(v) => write!(f, "invalid bool value: {}", v),
+            Error::InvalidChar => write!(f, "invalid char"),
+            Error::Utf8Error(e) => write!(f, "utf8 error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl From<&'static str> for Error {
+    fn from(e: &'static str) -> Self {
+        Error::BitStream(e)
+    }
+}
+
+impl From<std::str::Utf8Error> for Error {
+    fn from(e: std::str::Utf8Error) -> Self {
+        Error::Utf8Error(e)
+    }
+}
+
+#[inline]
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[inline]
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Sign-extend the low `bits` bits of `value` to a full `i64`, for `IntEncoding::Fixint`'s
+/// raw fixed-width reads (which otherwise come back zero-extended from `BitDecoder::read_bits`)
+#[inline]
+fn sign_extend(value: u64, bits: u32) -> i64 {
+    let shift = 64 - bits;
+    ((value << shift) as i64) >> shift
+}
+
+/// Serde `Serializer` that packs values bit-by-bit into a `BitEncoder` instead of writing
+/// whole bytes per field
+pub struct CompactSerializer {
+    enc: BitEncoder,
+    int_encoding: IntEncoding,
+}
+
+impl Default for CompactSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompactSerializer {
+    pub fn new() -> Self {
+        Self { enc: BitEncoder::new(), int_encoding: IntEncoding::Varint }
+    }
+
+    /// Construct a `CompactSerializer` using `config`'s integer encoding instead of the
+    /// default gamma coding
+    pub fn with_config(config: CompactConfig) -> Self {
+        Self { enc: BitEncoder::new(), int_encoding: config.int_encoding }
+    }
+
+    #[inline]
+    fn write_str_bytes(&mut self, bytes: &[u8]) {
+        self.enc.write_gamma(bytes.len() as u64);
+        for &byte in bytes {
+            self.enc.write_bits(byte as u64, 8);
+        }
+    }
+
+    /// Write a signed integer using this serializer's configured `int_encoding`
+    ///
+    /// `Leb128` has no meaning as a distinct bit-packed scheme - gamma coding already is this
+    /// module's variable-length integer representation - so it's treated as a synonym for
+    /// `Varint` here rather than rejected.
+    #[inline]
+    fn write_int(&mut self, v: i64, bits: u32) {
+        match self.int_encoding {
+            IntEncoding::Varint | IntEncoding::Leb128 => self.enc.write_gamma(zigzag_encode(v)),
+            IntEncoding::Fixint => self.enc.write_bits(v as u64, bits),
+        }
+    }
+
+    /// Write an unsigned integer using this serializer's configured `int_encoding`
+    #[inline]
+    fn write_uint(&mut self, v: u64, bits: u32) {
+        match self.int_encoding {
+            IntEncoding::Varint | IntEncoding::Leb128 => self.enc.write_gamma(v),
+            IntEncoding::Fixint => self.enc.write_bits(v, bits),
+        }
+    }
+}
+
+impl ser::Serializer for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    #[inline]
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.enc.write_bits(v as u64, 1);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.write_int(v as i64, 8);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.write_int(v as i64, 16);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.write_int(v as i64, 32);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.write_int(v, 64);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.write_uint(v as u64, 8);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.write_uint(v as u64, 16);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.write_uint(v as u64, 32);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.write_uint(v, 64);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.enc.write_bits(v.to_bits() as u64, 32);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.enc.write_bits(v.to_bits(), 64);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.enc.write_gamma(v as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.write_str_bytes(v.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.write_str_bytes(v);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<(), Error> {
+        self.enc.write_bits(0, 1);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+        self.enc.write_bits(1, 1);
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        self.enc.write_gamma(variant_index as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.enc.write_gamma(variant_index as u64);
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let len = len.ok_or_else(|| Error::Message("sequence length required".into()))?;
+        self.enc.write_gamma(len as u64);
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.enc.write_gamma(variant_index as u64);
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let len = len.ok_or_else(|| Error::Message("map length required".into()))?;
+        self.enc.write_gamma(len as u64);
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.enc.write_gamma(variant_index as u64);
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        key.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut CompactSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    #[inline]
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    #[inline]
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Serde `Deserializer` reading the bit-packed layout written by `CompactSerializer`
+pub struct CompactDeserializer<'a> {
+    dec: BitDecoder<'a>,
+    int_encoding: IntEncoding,
+}
+
+impl<'a> CompactDeserializer<'a> {
+    fn read_str_bytes(&mut self) -> Result<Vec<u8>, Error> {
+        let len = self.dec.read_gamma()? as usize;
+        let mut bytes = Vec::with_capacity(len);
+        for _ in 0..len {
+            bytes.push(self.dec.read_bits(8)? as u8);
+        }
+        Ok(bytes)
+    }
+
+    /// Read a signed integer using this deserializer's configured `int_encoding`
+    #[inline]
+    fn read_int(&mut self, bits: u32) -> Result<i64, Error> {
+        match self.int_encoding {
+            IntEncoding::Varint | IntEncoding::Leb128 => Ok(zigzag_decode(self.dec.read_gamma()?)),
+            IntEncoding::Fixint => Ok(sign_extend(self.dec.read_bits(bits)?, bits)),
+        }
+    }
+
+    /// Read an unsigned integer using this deserializer's configured `int_encoding`
+    #[inline]
+    fn read_uint(&mut self, bits: u32) -> Result<u64, Error> {
+        match self.int_encoding {
+            IntEncoding::Varint | IntEncoding::Leb128 => Ok(self.dec.read_gamma()?),
+            IntEncoding::Fixint => Ok(self.dec.read_bits(bits)?),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut CompactDeserializer<'de> {
+    type Error = Error;
+
+    #[inline]
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+        Err(Error::Message("deserialize_any not supported".into()))
+    }
+
+    #[inline]
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.dec.read_bits(1)? {
+            0 => visitor.visit_bool(false),
+            1 => visitor.visit_bool(true),
+            v => Err(Error::InvalidBool(v)),
+        }
+    }
+
+    #[inline]
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i8(self.read_int(8)? as i8)
+    }
+
+    #[inline]
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i16(self.read_int(16)? as i16)
+    }
+
+    #[inline]
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i32(self.read_int(32)? as i32)
+    }
+
+    #[inline]
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_i64(self.read_int(64)?)
+    }
+
+    #[inline]
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u8(self.read_uint(8)? as u8)
+    }
+
+    #[inline]
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u16(self.read_uint(16)? as u16)
+    }
+
+    #[inline]
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u32(self.read_uint(32)? as u32)
+    }
+
+    #[inline]
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u64(self.read_uint(64)?)
+    }
+
+    #[inline]
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_f32(f32::from_bits(self.dec.read_bits(32)? as u32))
+    }
+
+    #[inline]
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_f64(f64::from_bits(self.dec.read_bits(64)?))
+    }
+
+    #[inline]
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let v = self.dec.read_gamma()? as u32;
+        visitor.visit_char(char::from_u32(v).ok_or(Error::InvalidChar)?)
+    }
+
+    #[inline]
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let bytes = self.read_str_bytes()?;
+        visitor.visit_string(String::from_utf8(bytes).map_err(|e| e.utf8_error())?)
+    }
+
+    #[inline]
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    #[inline]
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_byte_buf(self.read_str_bytes()?)
+    }
+
+    #[inline]
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    #[inline]
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.dec.read_bits(1)? {
+            0 => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    #[inline]
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    #[inline]
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    #[inline]
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.dec.read_gamma()? as usize;
+        visitor.visit_seq(CompactSeqAccess { de: self, remaining: len })
+    }
+
+    #[inline]
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(CompactSeqAccess { de: self, remaining: len })
+    }
+
+    #[inline]
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(CompactSeqAccess { de: self, remaining: len })
+    }
+
+    #[inline]
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let len = self.dec.read_gamma()? as usize;
+        visitor.visit_map(CompactMapAccess { de: self, remaining: len })
+    }
+
+    #[inline]
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_seq(CompactSeqAccess { de: self, remaining: fields.len() })
+    }
+
+    #[inline]
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(CompactEnumAccess { de: self })
+    }
+
+    #[inline]
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    #[inline]
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+}
+
+struct CompactSeqAccess<'a, 'de> {
+    de: &'a mut CompactDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for CompactSeqAccess<'a, 'de> {
+    type Error = Error;
+
+    #[inline]
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct CompactMapAccess<'a, 'de> {
+    de: &'a mut CompactDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> MapAccess<'de> for CompactMapAccess<'a, 'de> {
+    type Error = Error;
+
+    #[inline]
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    #[inline]
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct CompactEnumAccess<'a, 'de> {
+    de: &'a mut CompactDeserializer<'de>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for CompactEnumAccess<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    #[inline]
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), Error> {
+        let variant_index = self.de.dec.read_gamma()? as u32;
+        let v = seed.deserialize(de::value::U32Deserializer::<Error>::new(variant_index))?;
+        Ok((v, self))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for CompactEnumAccess<'a, 'de> {
+    type Error = Error;
+
+    #[inline]
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    #[inline]
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_tuple(&mut *self.de, len, visitor)
+    }
+
+    #[inline]
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_struct(&mut *self.de, "", fields, visitor)
+    }
+}
+
+/// Serialize `value` into the bit-packed compact layout, prefixed with an 8-byte little-endian
+/// exact bit length (so `deserialize_compact` can hand `BitDecoder` the precise length without a
+/// separate out-of-band parameter)
+pub fn serialize_compact<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    serialize_compact_with_config(value, CompactConfig::default())
+}
+
+/// Inverse of `serialize_compact`
+pub fn deserialize_compact<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    deserialize_compact_with_config(bytes, CompactConfig::default())
+}
+
+/// `serialize_compact`, but with explicit control over `CompactConfig::int_encoding`
+pub fn serialize_compact_with_config<T: Serialize>(
+    value: &T,
+    config: CompactConfig,
+) -> Result<Vec<u8>, Error> {
+    let mut ser = CompactSerializer::with_config(config);
+    value.serialize(&mut ser)?;
+    let (bytes, total_bits) = ser.enc.finish();
+
+    let mut out = Vec::with_capacity(8 + bytes.len());
+    out.extend_from_slice(&total_bits.to_le_bytes());
+    out.extend_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Inverse of `serialize_compact_with_config` - `config` must match the one the bytes were
+/// written with, the same way a caller must already track which `IntEncoding` a payload uses
+pub fn deserialize_compact_with_config<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+    config: CompactConfig,
+) -> Result<T, Error> {
+    let header: [u8; 8] = bytes.get(..8).and_then(|s| s.try_into().ok()).ok_or(Error::Truncated)?;
+    let total_bits = u64::from_le_bytes(header);
+
+    let mut de = CompactDeserializer {
+        dec: BitDecoder::new(&bytes[8..], total_bits),
+        int_encoding: config.int_encoding,
+    };
+    T::deserialize(&mut de)
+}
+
+/// Shorter aliases for `serialize_compact`/`deserialize_compact`, for callers reaching for
+/// `limcode::pack`/`limcode::unpack` by bitcode's own naming convention
+pub use serialize_compact as pack;
+pub use deserialize_compact as unpack;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    struct Flags {
+        a: bool,
+        b: bool,
+        c: bool,
+        count: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Point,
+        Circle { radius: f64 },
+        Rect(u32, u32),
+    }
+
+    #[test]
+    fn test_serialize_compact_round_trips_a_bool_heavy_struct() {
+        let flags = Flags { a: true, b: false, c: true, count: 7 };
+        let bytes = serialize_compact(&flags).unwrap();
+        let decoded: Flags = deserialize_compact(&bytes).unwrap();
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    fn test_pack_unpack_are_aliases_for_serialize_compact_and_deserialize_compact() {
+        let flags = Flags { a: true, b: false, c: true, count: 7 };
+        let bytes = pack(&flags).unwrap();
+        assert_eq!(bytes, serialize_compact(&flags).unwrap());
+        let decoded: Flags = unpack(&bytes).unwrap();
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    fn test_serialize_compact_round_trips_enum_variants() {
+        for shape in [Shape::Point, Shape::Circle { radius: 2.5 }, Shape::Rect(3, 4)] {
+            let bytes = serialize_compact(&shape).unwrap();
+            let decoded: Shape = deserialize_compact(&bytes).unwrap();
+            assert_eq!(decoded, shape);
+        }
+    }
+
+    #[test]
+    fn test_serialize_compact_round_trips_strings_and_sequences() {
+        let value: Vec<String> = vec!["hello".into(), "".into(), "world".into()];
+        let bytes = serialize_compact(&value).unwrap();
+        let decoded: Vec<String> = deserialize_compact(&bytes).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_serialize_compact_packs_bools_smaller_than_byte_aligned_serialize() {
+        // Enough bools that the 8-byte bit-length header is amortized away by the packing win:
+        // 64 bools cost 8 bytes packed (plus the header) versus 64 bytes byte-aligned.
+        let value: Vec<bool> = (0..64).map(|i| i % 3 == 0).collect();
+
+        let compact = serialize_compact(&value).unwrap();
+        let byte_aligned = crate::serializer::serialize(&value).unwrap();
+
+        assert!(compact.len() < byte_aligned.len());
+    }
+
+    #[test]
+    fn test_compact_config_default_matches_serialize_compact() {
+        let flags = Flags { a: true, b: false, c: true, count: 7 };
+        let configured = serialize_compact_with_config(&flags, CompactConfig::default()).unwrap();
+        assert_eq!(configured, serialize_compact(&flags).unwrap());
+    }
+
+    #[test]
+    fn test_fixint_round_trips_a_bool_heavy_struct_and_enum() {
+        let config = CompactConfig { int_encoding: IntEncoding::Fixint };
+
+        let flags = Flags { a: true, b: false, c: true, count: 7 };
+        let bytes = serialize_compact_with_config(&flags, config).unwrap();
+        let decoded: Flags = deserialize_compact_with_config(&bytes, config).unwrap();
+        assert_eq!(decoded, flags);
+
+        for shape in [Shape::Point, Shape::Circle { radius: 2.5 }, Shape::Rect(3, 4)] {
+            let bytes = serialize_compact_with_config(&shape, config).unwrap();
+            let decoded: Shape = deserialize_compact_with_config(&bytes, config).unwrap();
+            assert_eq!(decoded, shape);
+        }
+    }
+
+    #[test]
+    fn test_fixint_round_trips_negative_and_boundary_integers() {
+        let config = CompactConfig { int_encoding: IntEncoding::Fixint };
+        let values: [i64; 6] = [0, -1, i8::MIN as i64, i16::MIN as i64, i32::MIN as i64, i64::MIN];
+
+        for &value in &values {
+            let bytes = serialize_compact_with_config(&value, config).unwrap();
+            let decoded: i64 = deserialize_compact_with_config(&bytes, config).unwrap();
+            assert_eq!(decoded, value);
+        }
+    }
+
+    #[test]
+    fn test_varint_round_trips_i64_min_and_u64_max() {
+        // The default IntEncoding::Varint path zigzag-/gamma-codes every integer; i64::MIN
+        // zigzags to exactly u64::MAX, which write_gamma can't represent as value+1 without a
+        // special case - make sure both the signed and unsigned boundary values survive it.
+        let i64_bytes = serialize_compact(&i64::MIN).unwrap();
+        let i64_decoded: i64 = deserialize_compact(&i64_bytes).unwrap();
+        assert_eq!(i64_decoded, i64::MIN);
+
+        let u64_bytes = serialize_compact(&u64::MAX).unwrap();
+        let u64_decoded: u64 = deserialize_compact(&u64_bytes).unwrap();
+        assert_eq!(u64_decoded, u64::MAX);
+    }
+
+    #[test]
+    fn test_varint_beats_fixint_for_small_integers() {
+        // Small values gamma-code to a handful of bits each, while Fixint always spends the
+        // full 32 bits regardless of magnitude.
+        let value: Vec<u32> = vec![1, 2, 3, 1, 2, 1];
+
+        let varint = serialize_compact_with_config(
+            &value,
+            CompactConfig { int_encoding: IntEncoding::Varint },
+        )
+        .unwrap();
+        let fixint = serialize_compact_with_config(
+            &value,
+            CompactConfig { int_encoding: IntEncoding::Fixint },
+        )
+        .unwrap();
+
+        assert!(varint.len() < fixint.len());
+    }
+}
@@ -3,7 +3,9 @@
 //! This wraps the existing C++ LimcodeDecoder (AVX-512 optimized)
 //! to provide serde trait support with maximum performance.
 
-use serde::de::{self, Deserialize, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+
+use crate::Endian;
 
 /// Error type for deserialization
 #[derive(Debug)]
@@ -13,6 +15,11 @@ pub enum Error {
     InvalidBool(u8),
     InvalidChar,
     Utf8Error(std::str::Utf8Error),
+    Io(std::io::Error),
+    /// A length prefix or read would exceed the configured `SizeLimit::Bounded` byte budget
+    SizeLimit { requested: u64, remaining: u64 },
+    /// `end()` found unconsumed bytes left over after a successful decode
+    TrailingBytes { remaining: usize },
 }
 
 impl std::fmt::Display for Error {
@@ -23,6 +30,17 @@ impl std::fmt::Display for Error {
             Error::InvalidBool(v) => write!(f, "invalid bool value: {}", v),
             Error::InvalidChar => write!(f, "invalid char"),
             Error::Utf8Error(e) => write!(f, "utf8 error: {}", e),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::SizeLimit { requested, remaining } => write!(
+                f,
+                "size limit exceeded: requested {} bytes but only {} remain in the budget",
+                requested, remaining
+            ),
+            Error::TrailingBytes { remaining } => write!(
+                f,
+                "{} trailing byte(s) left after decoding a value",
+                remaining
+            ),
         }
     }
 }
@@ -41,57 +59,399 @@ impl From<std::str::Utf8Error> for Error {
     }
 }
 
-/// Fast Deserializer - pure Rust for zero-copy support
-/// (C++ decoder available via Decoder API for direct use)
-pub struct Deserializer<'de> {
-    input: &'de [u8],
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Integer-encoding mode selectable via `Deserializer::with_config`
+///
+/// `Fixint` is the default and matches the crate's existing byte-identical bincode format.
+/// `Varint` decodes bincode's variable-length integer scheme (see `read_varint_u64`), which
+/// shrinks small numbers and collection lengths at the cost of a data-dependent width.
+/// `Leb128` decodes the unsigned/zigzag LEB128 scheme common to protobuf and DWARF (see
+/// `read_leb128_u64`) - unlike `Varint`'s single marker byte plus one fixed-width trailing
+/// field, LEB128 spends a continuation bit per 7-bit group, so it has no marker-byte ceiling
+/// and degrades more gracefully as magnitude grows one group at a time instead of jumping
+/// straight to the next fixed width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntEncoding {
+    #[default]
+    Fixint,
+    Varint,
+    Leb128,
+}
+
+/// A byte-budget guard against hostile length prefixes
+///
+/// `Unlimited` (the default) preserves today's behavior. `Bounded(n)` caps the total number
+/// of payload bytes this `Deserializer` will ever read to `n`, decremented on every consumed
+/// byte; any read or declared collection length that would exceed the remaining budget fails
+/// with `Error::SizeLimit` instead of triggering a multi-gigabyte allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SizeLimit {
+    #[default]
+    Unlimited,
+    Bounded(u64),
+}
+
+/// Widen an IEEE 754 binary16 (half-precision) bit pattern into an `f32`
+///
+/// Handles zero, subnormals, infinities and NaN per the binary16 layout (1 sign bit, 5
+/// exponent bits, 10 mantissa bits) by re-biasing the exponent from its 5-bit range to
+/// `f32`'s 8-bit range and left-shifting the mantissa into `f32`'s wider field.
+pub(crate) fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = (bits >> 15) as u32 & 0x1;
+    let exp = (bits >> 10) & 0x1f;
+    let mantissa = (bits & 0x3ff) as u32;
+
+    let bits32 = if exp == 0 {
+        if mantissa == 0 {
+            sign << 31
+        } else {
+            // Subnormal: normalize by shifting the mantissa until its leading bit lands in
+            // the implicit-one position, adjusting the exponent to match.
+            let mut mantissa = mantissa;
+            let mut exp_adjust: i32 = 0;
+            while mantissa & 0x400 == 0 {
+                mantissa <<= 1;
+                exp_adjust += 1;
+            }
+            mantissa &= 0x3ff;
+            let exp32 = (127 - 15 - exp_adjust + 1) as u32;
+            (sign << 31) | (exp32 << 23) | (mantissa << 13)
+        }
+    } else if exp == 0x1f {
+        // Infinity or NaN: binary16's all-ones exponent maps to f32's all-ones exponent.
+        (sign << 31) | (0xff << 23) | (mantissa << 13)
+    } else {
+        let exp32 = exp as u32 + (127 - 15);
+        (sign << 31) | (exp32 << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(bits32)
+}
+
+/// Source of bytes for a `Deserializer`
+///
+/// Mirrors bincode's `BincodeRead` split between `SliceReader` and `IoReader`: a slice source
+/// can hand out borrowed `&'de` data straight from its buffer, while a `std::io::Read` source
+/// has nothing to borrow from and must copy into a scratch buffer instead. `forward_read_str`/
+/// `forward_read_bytes` let each implementation pick the cheapest visitor call available to it.
+pub trait Reader<'de> {
+    fn read_byte(&mut self) -> Result<u8, Error>;
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error>;
+    fn forward_read_str<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error>;
+    fn forward_read_bytes<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error>;
+}
+
+/// Zero-copy `Reader` over a borrowed `&'de [u8]`
+pub struct SliceReader<'de> {
+    slice: &'de [u8],
     pos: usize,
 }
 
-impl<'de> Deserializer<'de> {
-    pub fn new(input: &'de [u8]) -> Self {
-        Self { input, pos: 0 }
+impl<'de> SliceReader<'de> {
+    fn new(slice: &'de [u8]) -> Self {
+        Self { slice, pos: 0 }
     }
 
     #[inline(always)]
-    fn read_u8(&mut self) -> Result<u8, Error> {
-        if self.pos >= self.input.len() {
+    fn read_raw(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.pos + len > self.slice.len() {
             return Err(Error::Eof);
         }
-        let v = self.input[self.pos];
+        let bytes = &self.slice[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+}
+
+impl<'de> Reader<'de> for SliceReader<'de> {
+    #[inline(always)]
+    fn read_byte(&mut self) -> Result<u8, Error> {
+        if self.pos >= self.slice.len() {
+            return Err(Error::Eof);
+        }
+        let v = self.slice[self.pos];
         self.pos += 1;
         Ok(v)
     }
 
     #[inline(always)]
-    fn read_bytes(&mut self, len: usize) -> Result<&'de [u8], Error> {
-        if self.pos + len > self.input.len() {
-            return Err(Error::Eof);
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let bytes = self.read_raw(buf.len())?;
+        buf.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn forward_read_str<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        let bytes = self.read_raw(len)?;
+        let s = std::str::from_utf8(bytes)?;
+        visitor.visit_borrowed_str(s)
+    }
+
+    #[inline]
+    fn forward_read_bytes<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        let bytes = self.read_raw(len)?;
+        visitor.visit_borrowed_bytes(bytes)
+    }
+}
+
+/// Owned `Reader` over any `std::io::Read` source (a socket, a file, ...)
+///
+/// Has no `'de`-tied buffer to borrow from, so `str`/`bytes` payloads are copied into an
+/// internal scratch buffer (reused across calls) and handed to the visitor as owned data.
+pub struct IoReader<R> {
+    reader: R,
+    scratch: Vec<u8>,
+}
+
+impl<R: std::io::Read> IoReader<R> {
+    fn new(reader: R) -> Self {
+        Self { reader, scratch: Vec::new() }
+    }
+}
+
+impl<'de, R: std::io::Read> Reader<'de> for IoReader<R> {
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, Error> {
+        let mut buf = [0u8; 1];
+        self.reader.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.reader.read_exact(buf)?;
+        Ok(())
+    }
+
+    fn forward_read_str<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.scratch.clear();
+        self.scratch.resize(len, 0);
+        self.reader.read_exact(&mut self.scratch)?;
+        let s = std::str::from_utf8(&self.scratch)?;
+        visitor.visit_str(s)
+    }
+
+    fn forward_read_bytes<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.scratch.clear();
+        self.scratch.resize(len, 0);
+        self.reader.read_exact(&mut self.scratch)?;
+        visitor.visit_byte_buf(std::mem::take(&mut self.scratch))
+    }
+}
+
+/// Adapts a `char` visitor to the `str`-shaped `forward_read_str` call used by `deserialize_char`
+struct CharVisitor<V>(V);
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CharVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.expecting(f)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let c = v.chars().next().ok_or_else(|| de::Error::custom("invalid char"))?;
+        self.0.visit_char(c)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        let c = v.chars().next().ok_or_else(|| de::Error::custom("invalid char"))?;
+        self.0.visit_char(c)
+    }
+}
+
+/// Fast Deserializer - pure Rust for zero-copy support
+/// (C++ decoder available via Decoder API for direct use)
+///
+/// Generic over its byte source via `R: Reader<'de>` - defaults to the zero-copy
+/// `SliceReader<'de>`, with `IoReader<R>` available via `Deserializer::with_reader` for
+/// decoding from a `std::io::Read` source.
+pub struct Deserializer<'de, R: Reader<'de> = SliceReader<'de>> {
+    reader: R,
+    int_encoding: IntEncoding,
+    limit: SizeLimit,
+    endian: Endian,
+    _marker: std::marker::PhantomData<&'de ()>,
+}
+
+impl<'de> Deserializer<'de, SliceReader<'de>> {
+    pub fn new(input: &'de [u8]) -> Self {
+        Self {
+            reader: SliceReader::new(input),
+            int_encoding: IntEncoding::Fixint,
+            limit: SizeLimit::Unlimited,
+            endian: Endian::Little,
+            _marker: std::marker::PhantomData,
         }
-        let slice = &self.input[self.pos..self.pos + len];
-        self.pos += len;
-        Ok(slice)
+    }
+
+    /// Construct a `Deserializer` using a non-default integer-encoding mode
+    pub fn with_config(input: &'de [u8], int_encoding: IntEncoding) -> Self {
+        Self {
+            reader: SliceReader::new(input),
+            int_encoding,
+            limit: SizeLimit::Unlimited,
+            endian: Endian::Little,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Construct a `Deserializer` bounded to a maximum of `limit` payload bytes
+    ///
+    /// Every byte consumed by the reader (and thus every length-prefixed `str`/`bytes`/`seq`/
+    /// `map`) is checked against this budget before it is read.
+    pub fn with_limit(input: &'de [u8], limit: u64) -> Self {
+        Self {
+            reader: SliceReader::new(input),
+            int_encoding: IntEncoding::Fixint,
+            limit: SizeLimit::Bounded(limit),
+            endian: Endian::Little,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Confirm the entire input was consumed, erroring on leftover bytes
+    ///
+    /// Mirrors bincode's `RejectTrailing` option: a successful decode followed by unconsumed
+    /// input usually means the data was framed wrong or the wrong type was used to decode it,
+    /// so callers that expect to own the whole buffer should call this after deserializing.
+    pub fn end(&self) -> Result<(), Error> {
+        let remaining = self.reader.slice.len() - self.reader.pos;
+        if remaining > 0 {
+            Err(Error::TrailingBytes { remaining })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Raw zero-copy byte read, only available over a slice source
+    ///
+    /// Used by the POD helpers below, which reinterpret the returned bytes as `&'de [T]`
+    /// without an intervening visitor.
+    #[inline]
+    fn read_raw_bytes(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        self.consume_limit(len)?;
+        self.reader.read_raw(len)
+    }
+}
+
+impl<'de, R: std::io::Read> Deserializer<'de, IoReader<R>> {
+    /// Construct a `Deserializer` that reads from a `std::io::Read` source instead of a slice
+    ///
+    /// `str`/`bytes` values fall back to `visit_str`/`visit_byte_buf` over an internal scratch
+    /// buffer, since there's no `'de`-tied buffer to borrow from.
+    pub fn with_reader(reader: R) -> Self {
+        Self {
+            reader: IoReader::new(reader),
+            int_encoding: IntEncoding::Fixint,
+            limit: SizeLimit::Unlimited,
+            endian: Endian::Little,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, R: Reader<'de>> Deserializer<'de, R> {
+    /// Select the byte order `deserialize_i16`/.../`deserialize_f64` expect fixed-width fields to
+    /// be written in (`Little` by default, matching bincode) - the read-side counterpart to
+    /// `Serializer::with_endian`
+    pub fn with_endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Reject a declared collection length that would exceed the remaining size-limit budget
+    ///
+    /// Every element consumes at least one byte on the wire, so a declared `len` is rejected
+    /// outright if it already exceeds the remaining budget - this catches a hostile `2^60`
+    /// length prefix before any `Vec::with_capacity` is attempted. Unlike `consume_limit`, this
+    /// doesn't decrement the budget: the bytes themselves are decremented as they're actually
+    /// read back out of the sequence or map.
+    #[inline]
+    fn check_len_limit(&self, len: usize) -> Result<(), Error> {
+        if let SizeLimit::Bounded(remaining) = self.limit {
+            if len as u64 > remaining {
+                return Err(Error::SizeLimit {
+                    requested: len as u64,
+                    remaining,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check and decrement the size-limit budget for `len` bytes about to be read
+    #[inline]
+    fn consume_limit(&mut self, len: usize) -> Result<(), Error> {
+        if let SizeLimit::Bounded(remaining) = self.limit {
+            if len as u64 > remaining {
+                return Err(Error::SizeLimit {
+                    requested: len as u64,
+                    remaining,
+                });
+            }
+            self.limit = SizeLimit::Bounded(remaining - len as u64);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn read_u8(&mut self) -> Result<u8, Error> {
+        self.consume_limit(1)?;
+        self.reader.read_byte()
+    }
+
+    #[inline(always)]
+    fn read_fixed<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        self.consume_limit(N)?;
+        let mut buf = [0u8; N];
+        self.reader.read_exact(&mut buf)?;
+        Ok(buf)
     }
 
     #[inline(always)]
     fn read_u16(&mut self) -> Result<u16, Error> {
-        let bytes = self.read_bytes(2)?;
-        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+        Ok(u16::from_le_bytes(self.read_fixed::<2>()?))
     }
 
     #[inline(always)]
     fn read_u32(&mut self) -> Result<u32, Error> {
-        let bytes = self.read_bytes(4)?;
-        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        Ok(u32::from_le_bytes(self.read_fixed::<4>()?))
     }
 
     #[inline(always)]
     fn read_u64(&mut self) -> Result<u64, Error> {
-        let bytes = self.read_bytes(8)?;
-        Ok(u64::from_le_bytes([
-            bytes[0], bytes[1], bytes[2], bytes[3],
-            bytes[4], bytes[5], bytes[6], bytes[7],
-        ]))
+        Ok(u64::from_le_bytes(self.read_fixed::<8>()?))
+    }
+
+    /// Read a fixed-width `u16`/`u32`/`u64`/`i16`/`i32`/`i64`/`f32`/`f64` field, honoring
+    /// `self.endian` - the read-side counterpart to `Serializer::write_fixed_u16`/etc.
+    ///
+    /// `read_u16`/`read_u32`/`read_u64` above always read little-endian bytes; swapping the
+    /// result afterward produces the same value a big-endian read of those bytes would, without
+    /// a second byte-order-aware read path.
+    #[inline(always)]
+    fn read_fixed_u16(&mut self) -> Result<u16, Error> {
+        let v = self.read_u16()?;
+        Ok(if self.endian == Endian::Big { v.swap_bytes() } else { v })
+    }
+
+    #[inline(always)]
+    fn read_fixed_u32(&mut self) -> Result<u32, Error> {
+        let v = self.read_u32()?;
+        Ok(if self.endian == Endian::Big { v.swap_bytes() } else { v })
+    }
+
+    #[inline(always)]
+    fn read_fixed_u64(&mut self) -> Result<u64, Error> {
+        let v = self.read_u64()?;
+        Ok(if self.endian == Endian::Big { v.swap_bytes() } else { v })
     }
 
     #[inline(always)]
@@ -101,31 +461,108 @@ impl<'de> Deserializer<'de> {
 
     #[inline(always)]
     fn read_i16(&mut self) -> Result<i16, Error> {
-        Ok(self.read_u16()? as i16)
+        Ok(self.read_fixed_u16()? as i16)
     }
 
     #[inline(always)]
     fn read_i32(&mut self) -> Result<i32, Error> {
-        Ok(self.read_u32()? as i32)
+        Ok(self.read_fixed_u32()? as i32)
     }
 
     #[inline(always)]
     fn read_i64(&mut self) -> Result<i64, Error> {
-        Ok(self.read_u64()? as i64)
+        Ok(self.read_fixed_u64()? as i64)
     }
 
     #[inline(always)]
     fn read_f32(&mut self) -> Result<f32, Error> {
-        Ok(f32::from_bits(self.read_u32()?))
+        Ok(f32::from_bits(self.read_fixed_u32()?))
     }
 
     #[inline(always)]
     fn read_f64(&mut self) -> Result<f64, Error> {
-        Ok(f64::from_bits(self.read_u64()?))
+        Ok(f64::from_bits(self.read_fixed_u64()?))
+    }
+
+    /// Read an IEEE 754 binary16 (half-precision) float, widened into an `f32`
+    ///
+    /// Not wired into any `serde::Deserializer` method, since serde has no `deserialize_f16` -
+    /// callers that know a field was written as half-precision (bandwidth-sensitive ML tensors,
+    /// graphics vertex data) call this directly instead of going through `Deserialize`.
+    #[inline(always)]
+    pub fn read_f16(&mut self) -> Result<f32, Error> {
+        Ok(f16_bits_to_f32(self.read_u16()?))
+    }
+
+    /// Read a bincode-compatible variable-length unsigned integer
+    ///
+    /// A value `v <= 250` is stored as a single byte. Larger values are prefixed with a
+    /// marker byte giving the trailing width: `251` -> 2-byte `u16`, `252` -> 4-byte `u32`,
+    /// `253` -> 8-byte `u64`, `254` -> 16-byte `u128` (erroring if it overflows `u64`).
+    #[inline]
+    pub fn read_varint_u64(&mut self) -> Result<u64, Error> {
+        let tag = self.read_u8()?;
+        match tag {
+            0..=250 => Ok(tag as u64),
+            251 => Ok(u16::from_le_bytes(self.read_fixed::<2>()?) as u64),
+            252 => Ok(u32::from_le_bytes(self.read_fixed::<4>()?) as u64),
+            253 => Ok(u64::from_le_bytes(self.read_fixed::<8>()?)),
+            254 => {
+                let bytes = self.read_fixed::<16>()?;
+                u64::try_from(u128::from_le_bytes(bytes))
+                    .map_err(|_| Error::Message("varint value overflows u64".into()))
+            }
+            255 => Err(Error::Message("invalid varint marker byte 255".into())),
+        }
+    }
+
+    /// Read a bincode-compatible variable-length signed integer (zig-zag encoded)
+    #[inline]
+    pub fn read_varint_i64(&mut self) -> Result<i64, Error> {
+        let u = self.read_varint_u64()?;
+        Ok(((u >> 1) as i64) ^ -((u & 1) as i64))
+    }
+
+    /// Read an unsigned LEB128 integer: each byte's low 7 bits are a group, low-to-high, with
+    /// the top bit (`0x80`) set on every group but the last
+    #[inline]
+    pub fn read_leb128_u64(&mut self) -> Result<u64, Error> {
+        let mut result: u64 = 0;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 64 {
+                return Err(Error::Message("leb128 value overflows u64".into()));
+            }
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Read a zigzag-then-LEB128-encoded signed integer, the inverse of `write_leb128_i64`
+    #[inline]
+    pub fn read_leb128_i64(&mut self) -> Result<i64, Error> {
+        let u = self.read_leb128_u64()?;
+        Ok(((u >> 1) as i64) ^ -((u & 1) as i64))
+    }
+
+    /// Read a length prefix using whichever integer encoding this deserializer was configured
+    /// with. All collection lengths (seq/map/str/bytes) go through this single path so that
+    /// varint mode applies uniformly.
+    #[inline]
+    fn read_len(&mut self) -> Result<usize, Error> {
+        match self.int_encoding {
+            IntEncoding::Fixint => Ok(self.read_u64()? as usize),
+            IntEncoding::Varint => Ok(self.read_varint_u64()? as usize),
+            IntEncoding::Leb128 => Ok(self.read_leb128_u64()? as usize),
+        }
     }
 }
 
-impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
+impl<'de, R: Reader<'de>> de::Deserializer<'de> for &mut Deserializer<'de, R> {
     type Error = Error;
 
     #[inline]
@@ -150,17 +587,36 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_i16(self.read_i16()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_i16()?,
+            IntEncoding::Varint => i16::try_from(self.read_varint_i64()?)
+                .map_err(|_| Error::Message("varint value overflows i16".into()))?,
+            IntEncoding::Leb128 => i16::try_from(self.read_leb128_i64()?)
+                .map_err(|_| Error::Message("leb128 value overflows i16".into()))?,
+        };
+        visitor.visit_i16(v)
     }
 
     #[inline]
     fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_i32(self.read_i32()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_i32()?,
+            IntEncoding::Varint => i32::try_from(self.read_varint_i64()?)
+                .map_err(|_| Error::Message("varint value overflows i32".into()))?,
+            IntEncoding::Leb128 => i32::try_from(self.read_leb128_i64()?)
+                .map_err(|_| Error::Message("leb128 value overflows i32".into()))?,
+        };
+        visitor.visit_i32(v)
     }
 
     #[inline]
     fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_i64(self.read_i64()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_i64()?,
+            IntEncoding::Varint => self.read_varint_i64()?,
+            IntEncoding::Leb128 => self.read_leb128_i64()?,
+        };
+        visitor.visit_i64(v)
     }
 
     #[inline]
@@ -170,17 +626,36 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_u16(self.read_u16()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_fixed_u16()?,
+            IntEncoding::Varint => u16::try_from(self.read_varint_u64()?)
+                .map_err(|_| Error::Message("varint value overflows u16".into()))?,
+            IntEncoding::Leb128 => u16::try_from(self.read_leb128_u64()?)
+                .map_err(|_| Error::Message("leb128 value overflows u16".into()))?,
+        };
+        visitor.visit_u16(v)
     }
 
     #[inline]
     fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_u32(self.read_u32()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_fixed_u32()?,
+            IntEncoding::Varint => u32::try_from(self.read_varint_u64()?)
+                .map_err(|_| Error::Message("varint value overflows u32".into()))?,
+            IntEncoding::Leb128 => u32::try_from(self.read_leb128_u64()?)
+                .map_err(|_| Error::Message("leb128 value overflows u32".into()))?,
+        };
+        visitor.visit_u32(v)
     }
 
     #[inline]
     fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        visitor.visit_u64(self.read_u64()?)
+        let v = match self.int_encoding {
+            IntEncoding::Fixint => self.read_fixed_u64()?,
+            IntEncoding::Varint => self.read_varint_u64()?,
+            IntEncoding::Leb128 => self.read_leb128_u64()?,
+        };
+        visitor.visit_u64(v)
     }
 
     #[inline]
@@ -195,19 +670,16 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        let len = self.read_u64()? as usize;
-        let bytes = self.read_bytes(len)?;
-        let s = std::str::from_utf8(bytes)?;
-        let c = s.chars().next().ok_or(Error::InvalidChar)?;
-        visitor.visit_char(c)
+        let len = self.read_len()?;
+        self.consume_limit(len)?;
+        self.reader.forward_read_str(len, CharVisitor(visitor))
     }
 
     #[inline]
     fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        let len = self.read_u64()? as usize;
-        let bytes = self.read_bytes(len)?;
-        let s = std::str::from_utf8(bytes)?;
-        visitor.visit_borrowed_str(s)
+        let len = self.read_len()?;
+        self.consume_limit(len)?;
+        self.reader.forward_read_str(len, visitor)
     }
 
     #[inline]
@@ -217,9 +689,9 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        let len = self.read_u64()? as usize;
-        let bytes = self.read_bytes(len)?;
-        visitor.visit_borrowed_bytes(bytes)
+        let len = self.read_len()?;
+        self.consume_limit(len)?;
+        self.reader.forward_read_bytes(len, visitor)
     }
 
     #[inline]
@@ -262,7 +734,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        let len = self.read_u64()? as usize;
+        let len = self.read_len()?;
+        self.check_len_limit(len)?;
         visitor.visit_seq(SeqDeserializer { de: self, remaining: len })
     }
 
@@ -283,7 +756,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
     #[inline]
     fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
-        let len = self.read_u64()? as usize;
+        let len = self.read_len()?;
+        self.check_len_limit(len)?;
         visitor.visit_map(MapDeserializer { de: self, remaining: len })
     }
 
@@ -318,12 +792,12 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     }
 }
 
-struct SeqDeserializer<'a, 'de> {
-    de: &'a mut Deserializer<'de>,
+struct SeqDeserializer<'a, 'de, R: Reader<'de>> {
+    de: &'a mut Deserializer<'de, R>,
     remaining: usize,
 }
 
-impl<'de, 'a> SeqAccess<'de> for SeqDeserializer<'a, 'de> {
+impl<'de, 'a, R: Reader<'de>> SeqAccess<'de> for SeqDeserializer<'a, 'de, R> {
     type Error = Error;
 
     #[inline]
@@ -341,12 +815,12 @@ impl<'de, 'a> SeqAccess<'de> for SeqDeserializer<'a, 'de> {
     }
 }
 
-struct MapDeserializer<'a, 'de> {
-    de: &'a mut Deserializer<'de>,
+struct MapDeserializer<'a, 'de, R: Reader<'de>> {
+    de: &'a mut Deserializer<'de, R>,
     remaining: usize,
 }
 
-impl<'de, 'a> MapAccess<'de> for MapDeserializer<'a, 'de> {
+impl<'de, 'a, R: Reader<'de>> MapAccess<'de> for MapDeserializer<'a, 'de, R> {
     type Error = Error;
 
     #[inline]
@@ -369,11 +843,11 @@ impl<'de, 'a> MapAccess<'de> for MapDeserializer<'a, 'de> {
     }
 }
 
-struct EnumDeserializer<'a, 'de> {
-    de: &'a mut Deserializer<'de>,
+struct EnumDeserializer<'a, 'de, R: Reader<'de>> {
+    de: &'a mut Deserializer<'de, R>,
 }
 
-impl<'de, 'a> de::EnumAccess<'de> for EnumDeserializer<'a, 'de> {
+impl<'de, 'a, R: Reader<'de>> de::EnumAccess<'de> for EnumDeserializer<'a, 'de, R> {
     type Error = Error;
     type Variant = Self;
 
@@ -385,7 +859,7 @@ impl<'de, 'a> de::EnumAccess<'de> for EnumDeserializer<'a, 'de> {
     }
 }
 
-impl<'de, 'a> de::VariantAccess<'de> for EnumDeserializer<'a, 'de> {
+impl<'de, 'a, R: Reader<'de>> de::VariantAccess<'de> for EnumDeserializer<'a, 'de, R> {
     type Error = Error;
 
     #[inline]
@@ -426,30 +900,181 @@ pub fn deserialize<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Erro
     from_bytes(bytes)
 }
 
-/// ACTUAL zero-copy POD deserialization - returns slice view (no allocation!)
-/// For borrowed data where you don't need owned Vec
+/// Deserialize `bytes` into an existing `T` in place, instead of building a fresh value and
+/// overwriting `*out` with it
+///
+/// For types whose `Deserialize` impl overrides `deserialize_in_place` (`serde`'s blanket impls
+/// for `Vec<T>` and `String` both do), this reuses `out`'s existing allocation rather than
+/// allocating a new one on every call - the generic counterpart to `deserialize_pod_into`, which
+/// does the same for the `PodType` fast path. Types without a custom `deserialize_in_place` fall
+/// back to `Deserialize::deserialize` followed by an assignment, same as calling `deserialize`
+/// directly.
+#[inline]
+pub fn deserialize_into<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+    out: &mut T,
+) -> Result<(), Error> {
+    let mut deserializer = Deserializer::new(bytes);
+    Deserialize::deserialize_in_place(&mut deserializer, out)
+}
+
+/// Deserialize a `T` previously written by `serializer::serialize_varint`
+#[inline]
+pub fn deserialize_varint<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = Deserializer::with_config(bytes, IntEncoding::Varint);
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserialize a `T` previously written by `serializer::serialize_leb128`
+#[inline]
+pub fn deserialize_leb128<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = Deserializer::with_config(bytes, IntEncoding::Leb128);
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserialize `bytes`, rejecting any length-prefixed collection whose decoded size would push
+/// total consumption past `max_len`
+///
+/// Thin convenience wrapper around `Deserializer::with_limit`, matching bincode's
+/// `with_limit::<N>()` - useful for parsing attacker-controlled input (a network message, an
+/// untrusted file) where a corrupt or hostile length prefix would otherwise drive an unbounded
+/// `Vec::with_capacity` allocation before any data has actually been validated.
+#[inline]
+pub fn deserialize_with_limit<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+    max_len: u64,
+) -> Result<T, Error> {
+    let mut deserializer = Deserializer::with_limit(bytes, max_len);
+    T::deserialize(&mut deserializer)
+}
+
+/// Deserialize a `T` previously written by `serializer::serialize_be` - every fixed-width integer
+/// and float field is read most-significant-byte first
+#[inline]
+pub fn deserialize_be<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = Deserializer::new(bytes).with_endian(Endian::Big);
+    T::deserialize(&mut deserializer)
+}
+
+/// Like `from_bytes`, but errors with `Error::TrailingBytes` if `bytes` isn't fully consumed
+#[inline]
+pub fn from_bytes_strict<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = Deserializer::new(bytes);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Same as from_bytes_strict - matches wincode interface
+#[inline]
+pub fn deserialize_strict<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    from_bytes_strict(bytes)
+}
+
+/// Decode one value from the front of `bytes`, returning it along with the unconsumed remainder
+///
+/// Lets a streaming caller decode consecutive framed messages out of one buffer by feeding the
+/// returned tail back in as the next call's input.
+#[inline]
+pub fn from_bytes_with_tail<'de, T: Deserialize<'de>>(
+    bytes: &'de [u8],
+) -> Result<(T, &'de [u8]), Error> {
+    let mut deserializer = Deserializer::new(bytes);
+    let value = T::deserialize(&mut deserializer)?;
+    let tail = &bytes[deserializer.reader.pos..];
+    Ok((value, tail))
+}
+
+/// Parallel counterpart to `serializer::serialize_vec_parallel`: decode a length-prefixed
+/// sequence of `T` with the per-element work fanned out across rayon threads
+///
+/// First performs a cheap sequential scan over the whole input, using `serde::de::IgnoredAny`
+/// to walk (but not allocate) each element and record its `(start, end)` byte range - this both
+/// builds the offset table and validates every element's bounds before any parallel work
+/// begins, so the decode stage below can index `bytes` directly without re-checking bounds.
+/// Each `(start, end)` range then decodes independently and concurrently into a preallocated
+/// `Vec<T>` slot via `rayon`.
+///
+/// Falls back to a single sequential `from_bytes` below `PARALLEL_THRESHOLD` elements, where the
+/// scan's own sequential cost isn't worth paying. For a `Vec<T>` of a fixed-size `PodType`,
+/// prefer `deserialize_pod` instead - its stride is already known up front, so it skips the
+/// scan entirely and decodes via one parallel-friendly memcpy.
+pub fn deserialize_vec_parallel<T: DeserializeOwned + Send>(bytes: &[u8]) -> Result<Vec<T>, Error> {
+    const PARALLEL_THRESHOLD: usize = 1_000_000;
+
+    let mut scan = Deserializer::new(bytes);
+    let len = scan.read_len()?;
+
+    if len < PARALLEL_THRESHOLD {
+        return from_bytes(bytes);
+    }
+
+    let mut offsets = Vec::with_capacity(len);
+    for _ in 0..len {
+        let start = scan.reader.pos;
+        IgnoredAny::deserialize(&mut scan)?;
+        offsets.push((start, scan.reader.pos));
+    }
+
+    use rayon::prelude::*;
+    offsets
+        .into_par_iter()
+        .map(|(start, end)| {
+            let mut item_de = Deserializer::new(&bytes[start..end]);
+            T::deserialize(&mut item_de)
+        })
+        .collect()
+}
+
+/// Deserialize a value of an owned type by reading it from a `std::io::Read` source
+///
+/// Unlike `from_bytes`, the decoded value can't borrow from the input: `str`/`bytes` fields are
+/// copied into an owned `String`/`Vec<u8>` via `IoReader`'s scratch buffer.
+#[inline]
+pub fn from_reader<T: serde::de::DeserializeOwned, R: std::io::Read>(reader: R) -> Result<T, Error> {
+    let mut deserializer = Deserializer::with_reader(reader);
+    T::deserialize(&mut deserializer)
+}
+
+/// Zero-copy POD deserialization - returns a slice view directly into `bytes` (no allocation)
+///
+/// Validates that the payload is properly aligned for `T` before reinterpreting it, returning an
+/// error instead of invoking undefined behavior when the buffer's address isn't a multiple of
+/// `align_of::<T>()`. `T` must implement `crate::serializer::PodType`, the crate's marker trait
+/// for types safe to reinterpret from raw bytes. Callers that want a misaligned buffer to still
+/// decode, instead of erroring out, should use `deserialize_pod_cow` for its `Cow::Owned`
+/// fallback.
 #[inline]
 pub fn deserialize_pod_borrowed<'de, T: crate::serializer::PodType>(
     bytes: &'de [u8]
 ) -> Result<&'de [T], Error> {
     let mut de = Deserializer::new(bytes);
 
-    // Read length prefix
     let len = de.read_u64()? as usize;
 
-    // Calculate byte length
     let elem_size = std::mem::size_of::<T>();
-    let byte_len = len * elem_size;
+    let byte_len = len
+        .checked_mul(elem_size)
+        .ok_or_else(|| Error::Message("POD length overflow".into()))?;
 
-    // Zero-copy read - just reinterpret the slice
-    let raw_bytes = de.read_bytes(byte_len)?;
+    let raw_bytes = de.read_raw_bytes(byte_len)?;
 
-    // Reinterpret &[u8] as &[T] (safe on little-endian for POD types)
-    let result = unsafe {
-        std::slice::from_raw_parts(raw_bytes.as_ptr() as *const T, len)
-    };
+    if raw_bytes.len() % elem_size != 0 {
+        return Err(Error::Message(
+            "payload length is not a multiple of size_of::<T>()".into(),
+        ));
+    }
 
-    Ok(result)
+    let align = std::mem::align_of::<T>();
+    if !(raw_bytes.as_ptr() as usize).is_multiple_of(align) {
+        return Err(Error::Message(format!(
+            "buffer is not aligned for T (requires {}-byte alignment)",
+            align
+        )));
+    }
+
+    // SAFETY: length, byte-length and alignment have all been validated above
+    Ok(unsafe { std::slice::from_raw_parts(raw_bytes.as_ptr() as *const T, len) })
 }
 
 /// POD deserialization returning Vec<T> (allocates and copies)
@@ -460,6 +1085,240 @@ pub fn deserialize_pod<T: crate::serializer::PodType>(bytes: &[u8]) -> Result<Ve
     Ok(slice.to_vec())  // Single allocation, optimized by LLVM
 }
 
+/// Counterpart to `crate::serializer::serialize_pod_le`: decodes bytes that are always
+/// little-endian, regardless of this host's own endianness
+///
+/// A no-op fast path on little-endian hosts (calls straight through to `deserialize_pod`); on
+/// big-endian hosts, byteswaps every element after the reinterpret cast via
+/// `crate::serializer::PodType::swap_bytes_pod` to undo the little-endian layout.
+#[cfg(target_endian = "little")]
+#[inline]
+pub fn deserialize_pod_le<T: crate::serializer::PodType>(bytes: &[u8]) -> Result<Vec<T>, Error> {
+    deserialize_pod(bytes)
+}
+
+#[cfg(target_endian = "big")]
+pub fn deserialize_pod_le<T: crate::serializer::PodType>(bytes: &[u8]) -> Result<Vec<T>, Error> {
+    let mut values = deserialize_pod::<T>(bytes)?;
+    for value in &mut values {
+        *value = value.swap_bytes_pod();
+    }
+    Ok(values)
+}
+
+/// Deserialize a POD slice produced by `serialize_pod_shortvec`
+///
+/// Reads the ShortVec-compatible varint length prefix (rejecting an overlong, overflowing, or
+/// non-canonical encoding, same as `deserialize_shortvec`), then copies the following bytes
+/// into a freshly-allocated `Vec<T>`. Unlike `deserialize_pod_borrowed`'s reinterpret cast, this
+/// copies byte-by-byte rather than casting the source slice to `*const T` directly: the header
+/// is a variable 1-3 bytes, so the payload's start isn't guaranteed to land on a `T`-aligned
+/// offset the way `deserialize_pod`'s fixed 8-byte header does.
+pub fn deserialize_pod_shortvec<T: crate::serializer::PodType>(
+    bytes: &[u8],
+) -> Result<Vec<T>, Error> {
+    let (len, header_len) =
+        crate::read_shortvec_len(bytes).map_err(|e| Error::Message(e.to_string()))?;
+
+    let elem_size = std::mem::size_of::<T>();
+    let byte_len = len * elem_size;
+
+    if bytes.len() < header_len + byte_len {
+        return Err(Error::Message(
+            "deserialize_pod_shortvec: buffer too small for declared length".to_string(),
+        ));
+    }
+
+    let payload = &bytes[header_len..header_len + byte_len];
+    let mut result = Vec::<T>::with_capacity(len);
+    unsafe {
+        std::ptr::copy_nonoverlapping(payload.as_ptr(), result.as_mut_ptr() as *mut u8, byte_len);
+        result.set_len(len);
+    }
+    Ok(result)
+}
+
+/// Decode a hex string produced by `serialize_pod_hex` back into `Vec<T>`
+///
+/// Validates every byte is an ASCII hex digit (`0-9`, `a-f`, `A-F`) and that `hex` has even
+/// length before handing the decoded bytes to `deserialize_pod`; any other input returns
+/// `Error::Message` rather than panicking or silently masking off invalid nibbles.
+pub fn deserialize_pod_hex<T: crate::serializer::PodType>(hex: &str) -> Result<Vec<T>, Error> {
+    let hex = hex.as_bytes();
+    if !hex.len().is_multiple_of(2) {
+        return Err(Error::Message(format!(
+            "deserialize_pod_hex: odd-length input ({} bytes)",
+            hex.len()
+        )));
+    }
+
+    let mut bytes = vec![0u8; hex.len() / 2];
+    hex_decode_into(hex, &mut bytes)?;
+    deserialize_pod::<T>(&bytes)
+}
+
+/// Transcode lowercase/uppercase hex ASCII in `src` into raw bytes in `dst`, which must be
+/// exactly `src.len() / 2` bytes (caller has already checked `src.len()` is even).
+///
+/// `pub(crate)` so `lib.rs`'s `from_hex`/`Decoder::read_hex` can reuse the same nibble decoding
+/// as `deserialize_pod_hex` for raw (non length-prefixed) byte buffers. Purely scalar - unlike
+/// the encode side's `serializer::hex_encode_into`, this crate doesn't carry a SIMD decode
+/// kernel, since validating arbitrary input one nibble at a time is inherently branchy.
+#[inline]
+pub(crate) fn hex_decode_into(src: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    debug_assert_eq!(dst.len(), src.len() / 2);
+
+    for (i, pair) in src.chunks_exact(2).enumerate() {
+        let hi = hex_nibble(pair[0])?;
+        let lo = hex_nibble(pair[1])?;
+        dst[i] = (hi << 4) | lo;
+    }
+    Ok(())
+}
+
+#[inline]
+fn hex_nibble(b: u8) -> Result<u8, Error> {
+    match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(Error::Message(format!(
+            "deserialize_pod_hex: invalid hex byte {:#04x}",
+            b
+        ))),
+    }
+}
+
+/// Alignment-checked zero-copy POD deserialization
+///
+/// Equivalent to `deserialize_pod_borrowed`: both return a slice view directly into `bytes` with
+/// no allocation or copy, and both validate that the payload is properly aligned for `T` before
+/// reinterpreting it, erroring instead of invoking undefined behavior when the buffer's address
+/// isn't a multiple of `align_of::<T>()`. `T` must implement `crate::serializer::PodType`, the
+/// crate's marker trait for types safe to reinterpret from raw bytes. Callers that want a
+/// misaligned buffer to still decode, instead of erroring out, should use `deserialize_pod_cow`
+/// for its `Cow::Owned` fallback.
+#[inline]
+pub fn deserialize_pod_ref<T: crate::serializer::PodType>(bytes: &[u8]) -> Result<&[T], Error> {
+    let mut de = Deserializer::new(bytes);
+
+    // Read length prefix
+    let len = de.read_u64()? as usize;
+
+    let elem_size = std::mem::size_of::<T>();
+    let byte_len = len
+        .checked_mul(elem_size)
+        .ok_or_else(|| Error::Message("POD length overflow".into()))?;
+
+    let raw_bytes = de.read_raw_bytes(byte_len)?;
+
+    if raw_bytes.len() % elem_size != 0 {
+        return Err(Error::Message(
+            "payload length is not a multiple of size_of::<T>()".into(),
+        ));
+    }
+
+    let align = std::mem::align_of::<T>();
+    if !(raw_bytes.as_ptr() as usize).is_multiple_of(align) {
+        return Err(Error::Message(format!(
+            "buffer is not aligned for T (requires {}-byte alignment)",
+            align
+        )));
+    }
+
+    // SAFETY: length, byte-length and alignment have all been validated above
+    Ok(unsafe { std::slice::from_raw_parts(raw_bytes.as_ptr() as *const T, len) })
+}
+
+/// Alignment-safe POD deserialization with a copying fallback
+///
+/// `deserialize_pod_borrowed` returns an error the moment the buffer's address isn't a multiple
+/// of `align_of::<T>()`. This checks that first: an aligned buffer gets the same zero-copy
+/// `Cow::Borrowed` path for free, while a
+/// misaligned one falls back to a `Cow::Owned` copy built element-by-element with
+/// `ptr::read_unaligned`, which has no alignment requirement. Either way the bytes come from
+/// `read_raw_bytes`, which already rejects a `byte_len` that would run past the end of `bytes`.
+#[inline]
+pub fn deserialize_pod_cow<'de, T: crate::serializer::PodType>(
+    bytes: &'de [u8],
+) -> Result<std::borrow::Cow<'de, [T]>, Error> {
+    let mut de = Deserializer::new(bytes);
+
+    let len = de.read_u64()? as usize;
+    let elem_size = std::mem::size_of::<T>();
+    let byte_len = len
+        .checked_mul(elem_size)
+        .ok_or_else(|| Error::Message("POD length overflow".into()))?;
+
+    let raw_bytes = de.read_raw_bytes(byte_len)?;
+
+    let align = std::mem::align_of::<T>();
+    if (raw_bytes.as_ptr() as usize).is_multiple_of(align) {
+        // SAFETY: length and alignment have both been validated above.
+        let slice = unsafe { std::slice::from_raw_parts(raw_bytes.as_ptr() as *const T, len) };
+        Ok(std::borrow::Cow::Borrowed(slice))
+    } else {
+        let mut owned = Vec::with_capacity(len);
+        for chunk in raw_bytes.chunks_exact(elem_size) {
+            // SAFETY: `read_unaligned` has no alignment requirement, and `raw_bytes` was
+            // already validated to hold exactly `len * elem_size` in-bounds bytes.
+            owned.push(unsafe { std::ptr::read_unaligned(chunk.as_ptr() as *const T) });
+        }
+        Ok(std::borrow::Cow::Owned(owned))
+    }
+}
+
+/// POD deserialization into a caller-supplied, fixed-capacity `&mut [T]`, returning the number
+/// of elements written
+///
+/// Unlike `deserialize_pod_into`, which grows `out: &mut Vec<T>` to fit, this never allocates -
+/// it errors with `Error::Message` if the decoded length exceeds `out.len()` rather than
+/// resizing anything, matching the base64 crate's `decode_slice`. Useful for a hot loop that
+/// already owns a reusable, worst-case-sized scratch buffer and wants to bound how much of it a
+/// single decode call is allowed to touch. For payloads too large to stage as one `&[u8]` at
+/// all, pair this with `crate::io::DecoderReader` instead, which serves the underlying bytes
+/// through `std::io::Read` without ever buffering more than one frame at a time.
+#[inline]
+pub fn deserialize_slice<T: crate::serializer::PodType>(
+    bytes: &[u8],
+    out: &mut [T],
+) -> Result<usize, Error> {
+    let slice = deserialize_pod_borrowed::<T>(bytes)?;
+    if slice.len() > out.len() {
+        return Err(Error::Message(format!(
+            "deserialize_slice: decoded length {} exceeds output slice of {} elements",
+            slice.len(),
+            out.len()
+        )));
+    }
+    out[..slice.len()].copy_from_slice(slice);
+    Ok(slice.len())
+}
+
+/// POD deserialization into a caller-supplied, reusable `Vec<T>`
+///
+/// Clears `out` and bulk-copies the payload via `copy_nonoverlapping`, reusing the existing
+/// allocation when its capacity already covers the decoded length. This is the decode-side
+/// counterpart to `serialize_pod_into` and avoids a fresh allocation on every call in tight
+/// request/response loops.
+#[inline]
+pub fn deserialize_pod_into<T: crate::serializer::PodType>(
+    bytes: &[u8],
+    out: &mut Vec<T>,
+) -> Result<(), Error> {
+    let slice = deserialize_pod_borrowed::<T>(bytes)?;
+
+    out.clear();
+    out.reserve(slice.len());
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(slice.as_ptr(), out.as_mut_ptr(), slice.len());
+        out.set_len(slice.len());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -486,4 +1345,399 @@ mod tests {
         let decoded: Vec<u8> = from_bytes(&bytes).unwrap();
         assert_eq!(data, decoded);
     }
+
+    #[test]
+    fn test_deserialize_pod_le_round_trips_through_serialize_pod_le() {
+        let data: Vec<u64> = vec![1, 0x0102030405060708, u64::MAX];
+        let bytes = crate::serializer::serialize_pod_le(&data).unwrap();
+        let decoded: Vec<u64> = deserialize_pod_le(&bytes).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_deserialize_vec_parallel_matches_sequential_for_pod_elements() {
+        let data: Vec<u64> = (0..2000).collect();
+        let bytes = crate::serializer::serialize(&data).unwrap();
+        let decoded: Vec<u64> = deserialize_vec_parallel(&bytes).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_deserialize_vec_parallel_matches_sequential_for_variable_length_elements() {
+        let data: Vec<TestStruct> = (0..500u64)
+            .map(|i| TestStruct {
+                a: i,
+                b: format!("item-{i}"),
+            })
+            .collect();
+        let bytes = crate::serializer::serialize(&data).unwrap();
+        let decoded: Vec<TestStruct> = deserialize_vec_parallel(&bytes).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_deserialize_pod_ref_round_trip() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..100).collect();
+        let bytes = serialize_pod(&data).unwrap();
+        let borrowed: &[u64] = deserialize_pod_ref(&bytes).unwrap();
+        assert_eq!(borrowed, &data[..]);
+    }
+
+    #[test]
+    fn test_deserialize_pod_ref_rejects_truncated_buffer() {
+        let data: Vec<u64> = (0..10).collect();
+        let bytes = crate::serializer::serialize_pod(&data).unwrap();
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(deserialize_pod_ref::<u64>(truncated).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_pod_borrowed_rejects_misaligned_buffer() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..100).collect();
+        let aligned = serialize_pod(&data).unwrap();
+
+        // Shift the payload one byte off of an 8-byte boundary so the length prefix - and thus
+        // the u64 payload right after it - is misaligned for `u64`.
+        let mut shifted = vec![0u8; aligned.len() + 1];
+        shifted[1..].copy_from_slice(&aligned);
+        let misaligned_bytes = &shifted[1..];
+        assert_ne!((misaligned_bytes.as_ptr() as usize) % std::mem::align_of::<u64>(), 0);
+
+        assert!(deserialize_pod_borrowed::<u64>(misaligned_bytes).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_pod_cow_borrows_when_aligned() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..100).collect();
+        let bytes = serialize_pod(&data).unwrap();
+        let cow = deserialize_pod_cow::<u64>(&bytes).unwrap();
+        assert_eq!(&*cow, &data[..]);
+        assert!(matches!(cow, std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_deserialize_pod_cow_copies_when_misaligned() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..100).collect();
+        let aligned = serialize_pod(&data).unwrap();
+
+        // Shift the payload one byte off of an 8-byte boundary so the length prefix - and thus
+        // the u64 payload right after it - is misaligned for `u64`.
+        let mut shifted = vec![0u8; aligned.len() + 1];
+        shifted[1..].copy_from_slice(&aligned);
+        let misaligned_bytes = &shifted[1..];
+        assert_ne!((misaligned_bytes.as_ptr() as usize) % std::mem::align_of::<u64>(), 0);
+
+        let cow = deserialize_pod_cow::<u64>(misaligned_bytes).unwrap();
+        assert_eq!(&*cow, &data[..]);
+        assert!(matches!(cow, std::borrow::Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_deserialize_pod_cow_rejects_truncated_buffer() {
+        let data: Vec<u64> = (0..10).collect();
+        let bytes = crate::serializer::serialize_pod(&data).unwrap();
+        let truncated = &bytes[..bytes.len() - 1];
+        assert!(deserialize_pod_cow::<u64>(truncated).is_err());
+    }
+
+    #[test]
+    fn test_varint_u64_single_byte() {
+        let bytes = [200u8];
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Varint);
+        assert_eq!(de.read_varint_u64().unwrap(), 200);
+    }
+
+    #[test]
+    fn test_varint_u64_marker_widths() {
+        // 251 marker -> 2-byte u16
+        let bytes = [251u8, 0x00, 0x01]; // 256
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Varint);
+        assert_eq!(de.read_varint_u64().unwrap(), 256);
+
+        // 253 marker -> 8-byte u64
+        let mut bytes = vec![253u8];
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Varint);
+        assert_eq!(de.read_varint_u64().unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_varint_i64_zigzag() {
+        let cases: [i64; 5] = [0, -1, 1, -128, 12345];
+        for &value in &cases {
+            // Build the zig-zag varint by hand to test the reader in isolation
+            let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+            let mut bytes = Vec::new();
+            if zigzag <= 250 {
+                bytes.push(zigzag as u8);
+            } else {
+                bytes.push(253);
+                bytes.extend_from_slice(&zigzag.to_le_bytes());
+            }
+            let mut de = Deserializer::with_config(&bytes, IntEncoding::Varint);
+            assert_eq!(de.read_varint_i64().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_varint_length_prefix_used_for_seq() {
+        // A seq of 3 u8 elements, with a varint (1-byte) length prefix instead of the
+        // fixed 8-byte one the default Fixint mode would require.
+        let bytes = vec![3u8, 10, 20, 30];
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Varint);
+        let decoded: Vec<u8> = Deserialize::deserialize(&mut de).unwrap();
+        assert_eq!(decoded, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_leb128_u64_single_byte() {
+        let bytes = [100u8];
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Leb128);
+        assert_eq!(de.read_leb128_u64().unwrap(), 100);
+    }
+
+    #[test]
+    fn test_leb128_u64_multi_byte_groups() {
+        // 300 = 0b1_0010_1100 -> low 7 bits 0x2C with continuation, then 0x02
+        let bytes = [0xACu8, 0x02];
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Leb128);
+        assert_eq!(de.read_leb128_u64().unwrap(), 300);
+    }
+
+    #[test]
+    fn test_leb128_i64_zigzag() {
+        let cases: [i64; 6] = [0, -1, 1, -64, 64, i64::MIN];
+        for &value in &cases {
+            let bytes = crate::serializer::serialize_leb128(&value).unwrap();
+            let mut de = Deserializer::with_config(&bytes, IntEncoding::Leb128);
+            assert_eq!(de.read_leb128_i64().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_leb128_length_prefix_used_for_seq() {
+        // A seq of 3 u8 elements, with a leb128 (1-byte) length prefix instead of the
+        // fixed 8-byte one the default Fixint mode would require.
+        let bytes = vec![3u8, 10, 20, 30];
+        let mut de = Deserializer::with_config(&bytes, IntEncoding::Leb128);
+        let decoded: Vec<u8> = Deserialize::deserialize(&mut de).unwrap();
+        assert_eq!(decoded, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_deserialize_pod_into_reuses_buffer() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..200).collect();
+        let bytes = serialize_pod(&data).unwrap();
+
+        let mut out: Vec<u64> = Vec::with_capacity(1024);
+        let original_capacity = out.capacity();
+        deserialize_pod_into(&bytes, &mut out).unwrap();
+
+        assert_eq!(out, data);
+        assert_eq!(out.capacity(), original_capacity, "should reuse allocation");
+
+        // Second call with smaller data should also reuse (not reallocate smaller)
+        let small: Vec<u64> = vec![1, 2, 3];
+        let small_bytes = serialize_pod(&small).unwrap();
+        deserialize_pod_into(&small_bytes, &mut out).unwrap();
+        assert_eq!(out, small);
+        assert_eq!(out.capacity(), original_capacity, "should reuse allocation");
+    }
+
+    #[test]
+    fn test_deserialize_slice_writes_into_fixed_buffer_and_rejects_too_small() {
+        use crate::serializer::serialize_pod;
+
+        let data: Vec<u64> = (0..50).collect();
+        let bytes = serialize_pod(&data).unwrap();
+
+        let mut out = [0u64; 50];
+        let written = deserialize_slice(&bytes, &mut out).unwrap();
+        assert_eq!(written, data.len());
+        assert_eq!(&out[..written], &data[..]);
+
+        let mut too_small = [0u64; 49];
+        assert!(deserialize_slice(&bytes, &mut too_small).is_err());
+    }
+
+    #[test]
+    fn test_size_limit_rejects_hostile_length_prefix() {
+        // A declared length of u64::MAX, as a hostile sender might send to force a
+        // multi-exabyte allocation, must be rejected by the budget check before any
+        // attempt to read or allocate that many bytes.
+        let bytes = bincode::serialize(&u64::MAX).unwrap();
+        let mut de = Deserializer::with_limit(&bytes, 1024);
+        let result: Result<Vec<u8>, _> = Deserialize::deserialize(&mut de);
+        match result {
+            // The 8-byte length prefix itself is read (and charged to the budget) before the
+            // declared element count is checked, so `remaining` reflects that first deduction.
+            Err(Error::SizeLimit { requested, remaining }) => {
+                assert_eq!(requested, u64::MAX);
+                assert_eq!(remaining, 1024 - 8);
+            }
+            other => panic!("expected Error::SizeLimit, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_size_limit_allows_payload_within_budget() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let bytes = bincode::serialize(&data).unwrap();
+        let mut de = Deserializer::with_limit(&bytes, 64);
+        let decoded: Vec<u8> = Deserialize::deserialize(&mut de).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_deserialize_with_limit_rejects_hostile_length_prefix() {
+        let bytes = bincode::serialize(&u64::MAX).unwrap();
+        let result: Result<Vec<u8>, _> = deserialize_with_limit(&bytes, 1024);
+        assert!(matches!(result, Err(Error::SizeLimit { .. })));
+    }
+
+    #[test]
+    fn test_deserialize_with_limit_allows_payload_within_budget() {
+        let data = vec![1u8, 2, 3, 4, 5];
+        let bytes = bincode::serialize(&data).unwrap();
+        let decoded: Vec<u8> = deserialize_with_limit(&bytes, 64).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_size_limit_decrements_across_multiple_reads() {
+        let data = (vec![1u8, 2, 3], vec![4u8, 5]);
+        let bytes = bincode::serialize(&data).unwrap();
+        // Exactly enough budget for both Vec<u8> fields (8-byte length prefix + elements each) -
+        // exercises that the budget is shared and decremented across successive reads, not
+        // reset per-field.
+        let exact_budget = bytes.len() as u64;
+        let mut de = Deserializer::with_limit(&bytes, exact_budget);
+        let decoded: (Vec<u8>, Vec<u8>) = Deserialize::deserialize(&mut de).unwrap();
+        assert_eq!(decoded, data);
+
+        let mut de = Deserializer::with_limit(&bytes, exact_budget - 1);
+        let result: Result<(Vec<u8>, Vec<u8>), _> = Deserialize::deserialize(&mut de);
+        assert!(matches!(result, Err(Error::SizeLimit { .. })));
+    }
+
+    #[test]
+    fn test_from_bytes_strict_accepts_exact_length() {
+        let data = 42u64;
+        let bytes = bincode::serialize(&data).unwrap();
+        let decoded: u64 = from_bytes_strict(&bytes).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_from_bytes_strict_rejects_trailing_bytes() {
+        let data = 42u64;
+        let mut bytes = bincode::serialize(&data).unwrap();
+        bytes.push(0xff);
+        match from_bytes_strict::<u64>(&bytes) {
+            Err(Error::TrailingBytes { remaining }) => assert_eq!(remaining, 1),
+            other => panic!("expected Error::TrailingBytes, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_with_tail_decodes_consecutive_messages() {
+        let first = 1u64;
+        let second = 2u64;
+        let mut bytes = bincode::serialize(&first).unwrap();
+        bytes.extend(bincode::serialize(&second).unwrap());
+
+        let (decoded_first, tail) = from_bytes_with_tail::<u64>(&bytes).unwrap();
+        assert_eq!(decoded_first, first);
+
+        let (decoded_second, tail) = from_bytes_with_tail::<u64>(tail).unwrap();
+        assert_eq!(decoded_second, second);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_into_matches_deserialize_and_reuses_the_vec_allocation() {
+        let data: Vec<u32> = vec![10, 20, 30];
+        let bytes = bincode::serialize(&data).unwrap();
+
+        let mut out: Vec<u32> = Vec::with_capacity(64);
+        let original_capacity = out.capacity();
+        deserialize_into(&bytes, &mut out).unwrap();
+
+        assert_eq!(out, data);
+        assert_eq!(out.capacity(), original_capacity);
+
+        // A second, shorter payload into the same `out` reuses the allocation and doesn't leave
+        // stale elements behind.
+        let shorter: Vec<u32> = vec![1];
+        let shorter_bytes = bincode::serialize(&shorter).unwrap();
+        deserialize_into(&shorter_bytes, &mut out).unwrap();
+        assert_eq!(out, shorter);
+        assert_eq!(out.capacity(), original_capacity);
+    }
+
+    #[test]
+    fn test_from_reader_decodes_owned_struct() {
+        let data = TestStruct { a: 7, b: "streamed".into() };
+        let bytes = bincode::serialize(&data).unwrap();
+        let decoded: TestStruct = from_reader(&bytes[..]).unwrap();
+        assert_eq!(data, decoded);
+    }
+
+    #[test]
+    fn test_from_reader_matches_slice_path_for_vec() {
+        let data = vec![1u64, 2, 3, 4, 5];
+        let bytes = bincode::serialize(&data).unwrap();
+        let decoded: Vec<u64> = from_reader(&bytes[..]).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_read_f16_decodes_known_bit_patterns() {
+        // 0x3c00 = 1.0, 0xc000 = -2.0, 0x0000 = 0.0, 0x8000 = -0.0
+        let bytes = [0x00, 0x3c, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x80];
+        let mut de = Deserializer::new(&bytes);
+        assert_eq!(de.read_f16().unwrap(), 1.0f32);
+        assert_eq!(de.read_f16().unwrap(), -2.0f32);
+        assert_eq!(de.read_f16().unwrap(), 0.0f32);
+        assert_eq!(de.read_f16().unwrap(), -0.0f32);
+    }
+
+    #[test]
+    fn test_read_f16_handles_subnormal_and_infinity() {
+        // 0x0001 = smallest positive subnormal, 0x7c00 = +inf, 0x7e00 = NaN
+        let bytes = [0x01, 0x00, 0x00, 0x7c, 0x00, 0x7e];
+        let mut de = Deserializer::new(&bytes);
+        let smallest_subnormal = de.read_f16().unwrap();
+        assert!(smallest_subnormal > 0.0 && smallest_subnormal < 1e-6);
+        assert_eq!(de.read_f16().unwrap(), f32::INFINITY);
+        assert!(de.read_f16().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_deserialize_pod_hex_round_trips_and_accepts_mixed_case() {
+        let data: Vec<u64> = vec![1, 2, 3, u64::MAX];
+        let hex = crate::serializer::serialize_pod_hex(&data).unwrap();
+        let decoded: Vec<u64> = deserialize_pod_hex(&hex).unwrap();
+        assert_eq!(decoded, data);
+
+        let upper = hex.to_uppercase();
+        let decoded_upper: Vec<u64> = deserialize_pod_hex(&upper).unwrap();
+        assert_eq!(decoded_upper, data);
+    }
+
+    #[test]
+    fn test_deserialize_pod_hex_rejects_odd_length_and_non_hex_bytes() {
+        assert!(deserialize_pod_hex::<u8>("abc").is_err());
+        assert!(deserialize_pod_hex::<u8>("zz").is_err());
+    }
 }
@@ -28,10 +28,52 @@
 //! dec.read_bytes(&mut buf).unwrap();
 //! ```
 
+pub mod bench;
+pub mod bitpack;
+pub mod buf;
+pub mod codec;
+pub mod compact;
+#[cfg(feature = "compression")]
+pub mod compress;
+pub mod deserializer;
+pub mod io;
+pub mod rlp;
+pub mod serializer;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "solana")]
+pub mod sigverify;
+#[cfg(feature = "solana")]
+pub mod snapshot;
+#[cfg(feature = "solana")]
+pub mod txparse;
 pub mod ultra_fast;
+pub mod value;
+pub mod varint;
+
+/// `#[derive(Encode, Decode, ColumnarPod)]`, re-exported from the `limcode-derive` proc-macro
+/// crate so callers don't need a separate dependency for the common case
+#[cfg(feature = "derive")]
+pub use limcode_derive::{ColumnarPod, Decode, Encode};
+
+/// The serde-compatible (de)serialization entry points, re-exported at the crate root so
+/// callers can write `limcode::serialize`/`limcode::deserialize_pod` directly instead of
+/// reaching into the `serializer`/`deserializer` submodules
+pub use deserializer::{
+    deserialize, deserialize_be, deserialize_leb128, deserialize_pod, deserialize_pod_shortvec,
+    deserialize_varint, deserialize_vec_parallel, deserialize_with_limit, from_reader,
+};
+pub use serializer::{
+    serialize, serialize_be, serialize_leb128, serialize_pod, serialize_pod_parallel,
+    serialize_into_fixed, serialize_pod_shortvec, serialize_streamed, serialize_varint,
+    serialize_vec_parallel, serialize_vec_parallel_vectored, to_writer,
+};
+pub use compact::{pack, unpack};
 
 // ==================== FFI Bindings ====================
 
+use std::fmt;
+use std::io::{Read, Write};
 use std::os::raw::c_int;
 
 // Opaque handle types
@@ -57,6 +99,7 @@ extern "C" {
     fn limcode_encoder_write_varint(encoder: *mut LimcodeEncoder, value: u64);
     fn limcode_encoder_size(encoder: *const LimcodeEncoder) -> usize;
     fn limcode_encoder_into_vec(encoder: *mut LimcodeEncoder, out_size: *mut usize) -> *mut u8;
+    fn limcode_encoder_clear(encoder: *mut LimcodeEncoder);
 
     // ==================== Decoder API ====================
     fn limcode_decoder_new(data: *const u8, len: usize) -> *mut LimcodeDecoder;
@@ -78,10 +121,71 @@ extern "C" {
         bytes: usize,
         out_offset: *mut usize,
     ) -> *mut u8;
+    // Writes the encoder's buffered bytes straight into `out` (capacity `out_cap`) instead of
+    // allocating a fresh one. Always sets `*out_size` to the encoded length (even when `out_cap`
+    // is too small, so the caller learns how much to grow by) and returns 0 on success, -1 if
+    // `out_cap < *out_size`.
+    fn limcode_encoder_into_slice(
+        encoder: *mut LimcodeEncoder,
+        out: *mut u8,
+        out_cap: usize,
+        out_size: *mut usize,
+    ) -> c_int;
+
+    // ==================== Runtime SIMD Dispatch ====================
+    // Tells the C++ bulk-copy routines behind `limcode_encoder_write_bytes`/
+    // `limcode_decoder_read_bytes` which bulk-copy variant to use for the rest of the process.
+    fn limcode_select_simd_level(level: c_int) -> c_int;
 }
 
 // ==================== End FFI Bindings ====================
 
+/// The SIMD width the C++ bulk-copy routines dispatch to at runtime, widest first
+///
+/// `build.rs` compiles the scalar/SSE4.2/AVX2/AVX-512 variants of the C++ copy routines
+/// unconditionally (no `-march`/`-mavx*` gating), so the same binary runs correctly on any x86_64
+/// host; `selected_simd_level` probes the actual CPU once via `is_x86_feature_detected!` and
+/// tells the C++ side which variant to use from then on. This replaces baking a feature level in
+/// at compile time, which could crash (`-march=native` built on a newer CPU than it runs on) or
+/// leave performance on the table (the conservative CI baseline run on a capable host).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum SimdLevel {
+    Avx512 = 3,
+    Avx2 = 2,
+    Sse42 = 1,
+    Scalar = 0,
+}
+
+static SIMD_LEVEL: std::sync::OnceLock<SimdLevel> = std::sync::OnceLock::new();
+
+/// Detect the host CPU's widest supported SIMD level and tell the C++ side to use it for bulk
+/// copies from now on. Detection and the FFI call happen exactly once per process (subsequent
+/// calls just return the cached result), so this is cheap to call from every `Encoder`/`Decoder`
+/// construction site rather than threading a flag through every caller.
+pub fn selected_simd_level() -> SimdLevel {
+    *SIMD_LEVEL.get_or_init(|| {
+        #[cfg(target_arch = "x86_64")]
+        let level = if is_x86_feature_detected!("avx512f") {
+            SimdLevel::Avx512
+        } else if is_x86_feature_detected!("avx2") {
+            SimdLevel::Avx2
+        } else if is_x86_feature_detected!("sse4.2") {
+            SimdLevel::Sse42
+        } else {
+            SimdLevel::Scalar
+        };
+
+        #[cfg(not(target_arch = "x86_64"))]
+        let level = SimdLevel::Scalar;
+
+        unsafe {
+            limcode_select_simd_level(level as c_int);
+        }
+        level
+    })
+}
+
 /// Ultra-fast bincode-compatible serialization with adaptive optimization
 ///
 /// STRATEGY (size-based optimization):
@@ -147,114 +251,11 @@ unsafe fn prefault_pages(ptr: *mut u8, len: usize) {
 /// Non-temporal memory copy for large blocks (>64KB)
 /// Uses streaming stores to bypass cache and maximize memory bandwidth
 ///
-/// Uses the best available SIMD:
-/// - AVX-512: 64-byte non-temporal stores (1 instruction per cache line)
-/// - AVX2: 32-byte non-temporal stores
-/// - SSE2: 16-byte non-temporal stores (fallback)
+/// Dispatches to the best SIMD tier the host CPU actually supports at runtime - see
+/// [`crate::serializer::cpu_capabilities`] and [`crate::serializer::CpuCapabilities`].
 #[inline(always)]
-#[allow(unused_mut)] // Parameters may not be mutated on all platforms
-unsafe fn fast_nt_memcpy(mut dst: *mut u8, mut src: *const u8, mut len: usize) {
-    #[cfg(target_arch = "x86_64")]
-    {
-        // Try AVX-512 path first (64-byte non-temporal stores)
-        #[cfg(target_feature = "avx512f")]
-        {
-            use core::arch::x86_64::*;
-
-            // Align to 64-byte boundary for AVX-512
-            while (dst as usize) & 63 != 0 && len >= 64 {
-                std::ptr::copy_nonoverlapping(src, dst, 64);
-                src = src.add(64);
-                dst = dst.add(64);
-                len -= 64;
-            }
-
-            // Process 128-byte chunks (2x AVX-512 stores per iteration)
-            while len >= 128 {
-                let zmm0 = _mm512_loadu_si512(src as *const _);
-                let zmm1 = _mm512_loadu_si512(src.add(64) as *const _);
-                _mm512_stream_si512(dst as *mut _, zmm0);
-                _mm512_stream_si512(dst.add(64) as *mut _, zmm1);
-
-                src = src.add(128);
-                dst = dst.add(128);
-                len -= 128;
-            }
-
-            _mm_sfence();
-        }
-
-        // AVX2 path (32-byte non-temporal stores)
-        #[cfg(all(target_feature = "avx2", not(target_feature = "avx512f")))]
-        {
-            use core::arch::x86_64::*;
-
-            // Align to 32-byte boundary
-            while (dst as usize) & 31 != 0 && len >= 32 {
-                std::ptr::copy_nonoverlapping(src, dst, 32);
-                src = src.add(32);
-                dst = dst.add(32);
-                len -= 32;
-            }
-
-            // Process 128-byte chunks (4x AVX2 stores)
-            while len >= 128 {
-                let ymm0 = _mm256_loadu_si256(src as *const __m256i);
-                let ymm1 = _mm256_loadu_si256(src.add(32) as *const __m256i);
-                let ymm2 = _mm256_loadu_si256(src.add(64) as *const __m256i);
-                let ymm3 = _mm256_loadu_si256(src.add(96) as *const __m256i);
-
-                _mm256_stream_si256(dst as *mut __m256i, ymm0);
-                _mm256_stream_si256(dst.add(32) as *mut __m256i, ymm1);
-                _mm256_stream_si256(dst.add(64) as *mut __m256i, ymm2);
-                _mm256_stream_si256(dst.add(96) as *mut __m256i, ymm3);
-
-                src = src.add(128);
-                dst = dst.add(128);
-                len -= 128;
-            }
-
-            _mm_sfence();
-        }
-
-        // SSE2 fallback path (16-byte non-temporal stores)
-        #[cfg(all(target_feature = "sse2", not(target_feature = "avx2")))]
-        {
-            use core::arch::x86_64::*;
-
-            // Align to 16-byte boundary
-            while (dst as usize) & 15 != 0 && len >= 16 {
-                std::ptr::copy_nonoverlapping(src, dst, 16);
-                src = src.add(16);
-                dst = dst.add(16);
-                len -= 16;
-            }
-
-            // Process 64-byte chunks (4x SSE2 stores)
-            while len >= 64 {
-                let xmm0 = _mm_loadu_si128(src as *const __m128i);
-                let xmm1 = _mm_loadu_si128(src.add(16) as *const __m128i);
-                let xmm2 = _mm_loadu_si128(src.add(32) as *const __m128i);
-                let xmm3 = _mm_loadu_si128(src.add(48) as *const __m128i);
-
-                _mm_stream_si128(dst as *mut __m128i, xmm0);
-                _mm_stream_si128(dst.add(16) as *mut __m128i, xmm1);
-                _mm_stream_si128(dst.add(32) as *mut __m128i, xmm2);
-                _mm_stream_si128(dst.add(48) as *mut __m128i, xmm3);
-
-                src = src.add(64);
-                dst = dst.add(64);
-                len -= 64;
-            }
-
-            _mm_sfence();
-        }
-    }
-
-    // Handle remaining bytes with standard memcpy
-    if len > 0 {
-        std::ptr::copy_nonoverlapping(src, dst, len);
-    }
+unsafe fn fast_nt_memcpy(dst: *mut u8, src: *const u8, len: usize) {
+    crate::serializer::fast_nt_memcpy(dst, src, len)
 }
 
 /// Ultra-fast deserialization - ZERO-COPY by default!
@@ -330,12 +331,185 @@ pub unsafe fn deserialize_bincode_unchecked(data: &[u8]) -> &[u8] {
     std::slice::from_raw_parts(data.as_ptr().add(8), len)
 }
 
+/// Maximum value a ShortVec length prefix can hold: Solana's `encode_len`/`decode_len` only
+/// ever handle a `u16`, so three 7-bit continuation bytes is both the cap and the most the
+/// format needs.
+const SHORTVEC_MAX_LEN: u64 = u16::MAX as u64;
+
+/// Encode a length as a Solana ShortVec-compatible varint (7-bit continuation encoding)
+///
+/// Repeatedly takes the low 7 bits of `n`, shifting right by 7. If bits remain after the
+/// shift, the continuation bit (0x80) is set and encoding continues; otherwise the final
+/// byte is written as-is. Produces 1 byte for `n < 0x80`, 2 bytes for `n < 0x4000`, 3 bytes
+/// for `n <= u16::MAX`. Errors if `n` exceeds `u16::MAX`, matching `ShortU16`'s range.
+#[inline]
+pub(crate) fn write_shortvec_len(n: usize, out: &mut Vec<u8>) -> Result<(), &'static str> {
+    let mut n = n as u64;
+    if n > SHORTVEC_MAX_LEN {
+        return Err("ShortVec length exceeds u16::MAX");
+    }
+    loop {
+        let mut elem = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(elem);
+            break;
+        } else {
+            elem |= 0x80;
+            out.push(elem);
+        }
+    }
+    Ok(())
+}
+
+/// Decode a Solana ShortVec-compatible varint length, returning `(value, bytes_consumed)`
+///
+/// Caps at 3 bytes / 16 bits like `write_shortvec_len`, and rejects a non-canonical
+/// encoding - one with a trailing continuation byte that re-encodes to fewer bytes than were
+/// actually consumed (e.g. a needless `0x80 0x00` for a length of `0`) - by re-running
+/// `write_shortvec_len` on the decoded value and requiring it reproduce exactly the bytes
+/// consumed, matching Solana's `decode_len`.
+#[inline]
+pub(crate) fn read_shortvec_len(data: &[u8]) -> Result<(usize, usize), &'static str> {
+    let mut result: u64 = 0;
+    let mut shift = 0u32;
+
+    for (i, &byte) in data.iter().enumerate() {
+        if i >= 3 {
+            return Err("ShortVec length exceeds 3 bytes");
+        }
+        result |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            if result > SHORTVEC_MAX_LEN {
+                return Err("ShortVec length exceeds u16::MAX");
+            }
+            let consumed = i + 1;
+            let mut canonical = Vec::with_capacity(consumed);
+            write_shortvec_len(result as usize, &mut canonical)
+                .expect("just checked result <= SHORTVEC_MAX_LEN");
+            if canonical.len() != consumed {
+                return Err("Non-canonical ShortVec length encoding");
+            }
+            return Ok((result as usize, consumed));
+        }
+        shift += 7;
+    }
+
+    Err("Buffer too small")
+}
+
+/// Serialize raw bytes with a Solana ShortVec-compatible varint length prefix
+///
+/// Unlike `serialize_bincode`, which always writes a fixed 8-byte `u64` length, this
+/// encodes the length as a 7-bit continuation varint. Small-to-medium buffers (the common
+/// Solana transaction case) need only 1-3 bytes of header instead of 8, and the wire format
+/// matches Solana's `ShortVec` byte-for-byte. Errors if `data` is longer than `u16::MAX`
+/// bytes, since `ShortVec` has no encoding for a larger length.
+#[inline]
+pub fn serialize_shortvec(data: &[u8]) -> Result<Vec<u8>, &'static str> {
+    let mut out = Vec::with_capacity(data.len() + 2);
+    write_shortvec_len(data.len(), &mut out)?;
+    out.extend_from_slice(data);
+    Ok(out)
+}
+
+/// Deserialize bytes produced by `serialize_shortvec`
+///
+/// Returns a borrowed slice into `data`, matching `deserialize_bincode`'s zero-copy style.
+#[inline]
+pub fn deserialize_shortvec(data: &[u8]) -> Result<&[u8], &'static str> {
+    let (len, header_len) = read_shortvec_len(data)?;
+
+    if data.len() < header_len + len {
+        return Err("Buffer too small");
+    }
+
+    Ok(&data[header_len..header_len + len])
+}
+
+/// Transcode `data` to a lowercase hex ASCII string, two output characters per input byte
+///
+/// Unlike `serializer::serialize_pod_hex`, this has no length prefix or element type - it's a
+/// direct byte-for-byte hex dump, matching `Encoder::write_hex`'s framing so `from_hex` (or
+/// `Decoder::read_hex`, which already knows how many bytes it expects) can reverse it. Reuses
+/// the same vectorized nibble-to-ASCII transcoding (`avx2`: 32-byte lanes, `ssse3`: 16-byte
+/// lanes, scalar fallback otherwise) that backs `serialize_pod_hex`.
+pub fn to_hex(data: &[u8]) -> String {
+    let mut out = vec![0u8; data.len() * 2];
+    serializer::hex_encode_into(data, &mut out);
+    // SAFETY: `hex_encode_into` only ever writes bytes out of its hex digit table, which are
+    // all valid single-byte ASCII/UTF-8.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Decode a hex string produced by `to_hex` back into raw bytes
+///
+/// Errors if `hex` has odd length or contains a byte that isn't an ASCII hex digit
+/// (`0-9`, `a-f`, `A-F`), rather than panicking or silently masking off invalid nibbles.
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, &'static str> {
+    let hex = hex.as_bytes();
+    if !hex.len().is_multiple_of(2) {
+        return Err("from_hex: odd-length input");
+    }
+
+    let mut bytes = vec![0u8; hex.len() / 2];
+    deserializer::hex_decode_into(hex, &mut bytes).map_err(|_| "from_hex: invalid hex digit")?;
+    Ok(bytes)
+}
+
+/// Size of the bounded chunk used to stream payload bytes through `serialize_into`
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Stream bincode-compatible serialization directly into a `std::io::Write`
+///
+/// Writes the same u64 little-endian length prefix + raw data format as
+/// `serialize_bincode`, but without ever materializing the combined buffer. The payload is
+/// written in bounded `STREAM_CHUNK_SIZE` chunks so callers can serialize directly into a
+/// socket or `BufWriter` without doubling memory usage.
+pub fn serialize_into<W: Write>(data: &[u8], writer: &mut W) -> std::io::Result<()> {
+    writer.write_all(&(data.len() as u64).to_le_bytes())?;
+    for chunk in data.chunks(STREAM_CHUNK_SIZE) {
+        writer.write_all(chunk)?;
+    }
+    Ok(())
+}
+
+/// Stream bincode-compatible deserialization from a `std::io::Read`
+///
+/// Reads the u64 length prefix written by `serialize_into`/`serialize_bincode`, then
+/// `read_exact`s the payload into a freshly allocated buffer. Works incrementally against
+/// any `Read` source (file, socket, pipe) without requiring the full stream to be buffered
+/// up front.
+pub fn deserialize_from<R: Read>(reader: &mut R) -> std::io::Result<Vec<u8>> {
+    let mut len_bytes = [0u8; 8];
+    reader.read_exact(&mut len_bytes)?;
+    let len = u64::from_le_bytes(len_bytes) as usize;
+
+    let mut data = vec![0u8; len];
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+/// Byte order for multi-byte primitive I/O on `Encoder`/`Decoder`
+///
+/// Mirrors bincode's `config/endian.rs` split: the default is `Little` (matching the FFI's
+/// native behavior), with `Big` available for interop with formats that mandate network byte
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
 /// High-performance binary encoder with SIMD optimizations
 pub struct Encoder {
     // Lazy-initialized C++ encoder (only created when needed for large buffers)
     inner: Option<*mut LimcodeEncoder>,
     // Reusable buffer for fast path - accumulates data, only flushed to C++ in finish()
     fast_buffer: Vec<u8>,
+    // Byte order for write_u16/write_u32/write_u64; see `with_endian`
+    endian: Endian,
 }
 
 impl Default for Encoder {
@@ -351,15 +525,42 @@ impl Encoder {
         Self {
             inner: None,
             fast_buffer: Vec::new(),
+            endian: Endian::default(),
+        }
+    }
+
+    /// Select the byte order `write_u16`/`write_u32`/`write_u64` use for this encoder
+    pub fn with_endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Create a new encoder whose `fast_buffer` is pre-allocated to hold at least `capacity`
+    /// bytes before the first write forces a reallocation
+    ///
+    /// Pair with [`crate::serializer::serialized_size`] to allocate exactly once for a known
+    /// payload (e.g. a batch of transactions) instead of letting `fast_buffer` grow incrementally.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: None,
+            fast_buffer: Vec::with_capacity(capacity),
+            endian: Endian::default(),
         }
     }
 
+    /// Reserve space in `fast_buffer` for at least `additional` more bytes, without over-allocating
+    /// the way `Vec::reserve`'s amortized growth would
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.fast_buffer.reserve_exact(additional);
+    }
+
     /// Get or create the C++ encoder (lazy initialization)
     #[inline]
     fn get_or_create_inner(&mut self) -> *mut LimcodeEncoder {
         if let Some(inner) = self.inner {
             inner
         } else {
+            selected_simd_level();
             unsafe {
                 let inner = limcode_encoder_new();
                 assert!(!inner.is_null(), "Failed to create encoder");
@@ -376,22 +577,34 @@ impl Encoder {
         }
     }
 
-    /// Write a u16 (little-endian)
+    /// Write a u16, honoring the encoder's configured `Endian` (`Little` by default)
     pub fn write_u16(&mut self, value: u16) {
+        let value = match self.endian {
+            Endian::Little => value.to_le(),
+            Endian::Big => value.to_be(),
+        };
         unsafe {
             limcode_encoder_write_u16(self.get_or_create_inner(), value);
         }
     }
 
-    /// Write a u32 (little-endian)
+    /// Write a u32, honoring the encoder's configured `Endian` (`Little` by default)
     pub fn write_u32(&mut self, value: u32) {
+        let value = match self.endian {
+            Endian::Little => value.to_le(),
+            Endian::Big => value.to_be(),
+        };
         unsafe {
             limcode_encoder_write_u32(self.get_or_create_inner(), value);
         }
     }
 
-    /// Write a u64 (little-endian)
+    /// Write a u64, honoring the encoder's configured `Endian` (`Little` by default)
     pub fn write_u64(&mut self, value: u64) {
+        let value = match self.endian {
+            Endian::Little => value.to_le(),
+            Endian::Big => value.to_be(),
+        };
         unsafe {
             limcode_encoder_write_u64(self.get_or_create_inner(), value);
         }
@@ -432,6 +645,145 @@ impl Encoder {
         }
     }
 
+    /// Pure-Rust LEB128 encode of an unsigned value, written directly into `fast_buffer`
+    ///
+    /// Reserves the worst-case encoded width (`MAX_ENCODED_LEN = ceil(64 / 7) = 10` bytes) up
+    /// front and writes into the buffer's uninitialized tail via `set_len`, skipping the
+    /// per-byte bounds checks a naive push loop would pay - same trick as `write_vec_bincode`'s
+    /// fast path. Each byte holds 7 bits of value with the high bit set as a continuation flag.
+    #[inline]
+    fn write_uvarint_fast(&mut self, mut value: u64) {
+        const MAX_ENCODED_LEN: usize = 10;
+        self.fast_buffer.reserve(MAX_ENCODED_LEN);
+
+        let start = self.fast_buffer.len();
+        let mut written = 0;
+        unsafe {
+            let ptr = self.fast_buffer.as_mut_ptr().add(start);
+            loop {
+                let mut byte = (value & 0x7f) as u8;
+                value >>= 7;
+                if value != 0 {
+                    byte |= 0x80;
+                }
+                std::ptr::write(ptr.add(written), byte);
+                written += 1;
+                if value == 0 {
+                    break;
+                }
+            }
+            self.fast_buffer.set_len(start + written);
+        }
+    }
+
+    /// Pure-Rust unsigned LEB128 varint write - unlike `write_varint`, never crosses the FFI
+    /// boundary into the C++ encoder
+    pub fn write_varint_fast(&mut self, value: u64) {
+        self.write_uvarint_fast(value);
+    }
+
+    /// Write a `u16` length using Solana's "short_vec" compact-u16 encoding
+    ///
+    /// Distinct from this crate's LEB128 `write_varint`: same per-byte
+    /// `7-bits-plus-continuation-bit` shape, but at most 3 bytes since it only ever encodes a
+    /// `u16`. Anything that serializes real Solana `Transaction` bytes (array lengths in the
+    /// wire format) needs this exact encoding rather than `write_varint` to stay byte-compatible.
+    /// Delegates to the same `write_shortvec_len` that backs `serialize_shortvec`, writing
+    /// straight into `fast_buffer` - never crosses the FFI boundary, same as `write_varint_fast`.
+    /// Pairs with `Decoder::read_shortu16`.
+    pub fn write_shortu16(&mut self, value: u16) {
+        write_shortvec_len(value as usize, &mut self.fast_buffer)
+            .expect("a u16 always fits ShortVec's u16::MAX limit");
+    }
+
+    /// Pure-Rust signed LEB128 varint write (zigzag-encoded, no FFI boundary crossing)
+    ///
+    /// Maps `value` to an unsigned integer via `(n << 1) ^ (n >> 63)` before delegating to the
+    /// same unsigned encoder, so small magnitudes in either direction stay compact.
+    pub fn write_svarint(&mut self, value: i64) {
+        let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        self.write_uvarint_fast(zigzag);
+    }
+
+    /// Signed LEB128 varint write (zigzag-encoded) over the FFI-backed `write_varint`
+    ///
+    /// Same zigzag mapping as `write_svarint` (`(n << 1) ^ (n >> 63)`), but composed with
+    /// `write_varint` instead of the `fast_buffer`-only path, so it's safe to interleave with
+    /// other FFI-backed writes on the same `Encoder` without hitting the ordering hazard
+    /// documented in `codec.rs`.
+    pub fn write_varint_signed(&mut self, value: i64) {
+        let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        self.write_varint(zigzag);
+    }
+
+    /// Write `data` as a lowercase hex-ASCII string, two output bytes per input byte, with no
+    /// length prefix - the decoder must already know how many bytes it expects, same as
+    /// `write_bytes`
+    ///
+    /// Useful for debuggable wire dumps and hex-oriented tooling that need the encoded stream to
+    /// stay human-readable. Delegates to `to_hex` then `write_bytes`, so it crosses the FFI
+    /// boundary the same way any other `write_bytes` call does. Pairs with `Decoder::read_hex`.
+    pub fn write_hex(&mut self, data: &[u8]) {
+        self.write_bytes(to_hex(data).as_bytes());
+    }
+
+    /// Write a string as a LEB128 length prefix, its raw UTF-8 bytes, then a `STR_SENTINEL`
+    /// byte the decoder can check to confirm it landed back on a string boundary
+    ///
+    /// Pairs with `Decoder::read_str`/`read_str_validated`, which skip or pay for UTF-8
+    /// re-validation respectively.
+    pub fn write_str(&mut self, value: &str) {
+        self.write_varint(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+        self.write_u8(STR_SENTINEL);
+    }
+
+    /// Write a raw f32 (bit pattern in the encoder's configured `Endian`, always 4 bytes)
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+
+    /// Write an f32 using a short tag byte plus only the significant bits for common cases
+    /// (exact zero, small integer-valued floats), falling back to the raw 4-byte bit pattern
+    /// for everything else (including NaN/infinity, so adversarial input never loses precision)
+    ///
+    /// Pairs with `Decoder::read_f32_compact`.
+    pub fn write_f32_compact(&mut self, value: f32) {
+        if value.to_bits() == 0 {
+            self.write_u8(FLOAT_TAG_ZERO);
+            return;
+        }
+        if let Some(as_int) = compact_int_for_f32(value) {
+            self.write_u8(FLOAT_TAG_INT);
+            self.write_varint(zigzag_encode(as_int));
+            return;
+        }
+        self.write_u8(FLOAT_TAG_FULL);
+        self.write_u32(value.to_bits());
+    }
+
+    /// Write a raw f64 (bit pattern in the encoder's configured `Endian`, always 8 bytes)
+    pub fn write_f64(&mut self, value: f64) {
+        self.write_u64(value.to_bits());
+    }
+
+    /// Like `write_f32_compact`, for f64
+    ///
+    /// Pairs with `Decoder::read_f64_compact`.
+    pub fn write_f64_compact(&mut self, value: f64) {
+        if value.to_bits() == 0 {
+            self.write_u8(FLOAT_TAG_ZERO);
+            return;
+        }
+        if let Some(as_int) = compact_int_for_f64(value) {
+            self.write_u8(FLOAT_TAG_INT);
+            self.write_varint(zigzag_encode(as_int));
+            return;
+        }
+        self.write_u8(FLOAT_TAG_FULL);
+        self.write_u64(value.to_bits());
+    }
+
     /// Write Vec<u8> with bincode-compatible format (u64 length prefix + data)
     /// This matches bincode's default serialization for Vec<u8>
     ///
@@ -568,6 +920,88 @@ impl Encoder {
             }
         }
     }
+
+    /// Finish encoding into a caller-provided buffer, then reset this encoder for reuse
+    ///
+    /// The FFI-backed counterpart to `serializer::serialize_into`: instead of consuming `self`
+    /// and handing back a fresh `Vec` (as `finish` does), this clears `out` and appends the
+    /// encoded bytes into it, then resets `self` in place - clearing `fast_buffer` and, if a C++
+    /// encoder was created for this payload, resetting it via `limcode_encoder_clear` instead of
+    /// dropping and recreating it. A hot loop that calls `write_*`/`finish_into` on the same
+    /// `Encoder` repeatedly pays for `limcode_encoder_new` at most once instead of once per
+    /// iteration.
+    pub fn finish_into(&mut self, out: &mut Vec<u8>) {
+        out.clear();
+
+        match self.inner {
+            None => {
+                out.append(&mut self.fast_buffer);
+            }
+            Some(inner) => unsafe {
+                if !self.fast_buffer.is_empty() {
+                    limcode_encoder_write_bytes(
+                        inner,
+                        self.fast_buffer.as_ptr(),
+                        self.fast_buffer.len(),
+                    );
+                    self.fast_buffer.clear();
+                }
+
+                let mut written = 0;
+                let ptr = limcode_encoder_into_vec(inner, &mut written);
+                if !ptr.is_null() {
+                    out.extend_from_slice(std::slice::from_raw_parts(ptr, written));
+                    limcode_free_buffer(ptr);
+                }
+
+                limcode_encoder_clear(inner);
+            },
+        }
+    }
+
+    /// Finish encoding into a caller-provided `&mut [u8]`, writing zero additional bytes to the
+    /// heap, then reset this encoder for reuse
+    ///
+    /// The true zero-allocation counterpart to `finish_into` - useful for batch workloads (e.g.
+    /// a bench loop serializing thousands of transactions) that already own a scratch buffer
+    /// sized for the largest payload and want every `finish` in the loop to cost nothing but the
+    /// copy. Returns the number of bytes written on success. If `out` is too small, returns
+    /// `Err(required_len)` without writing anything or losing the buffered data, so the caller
+    /// can grow `out` and retry.
+    pub fn finish_into_slice(&mut self, out: &mut [u8]) -> Result<usize, usize> {
+        match self.inner {
+            None => {
+                let len = self.fast_buffer.len();
+                if len > out.len() {
+                    return Err(len);
+                }
+                out[..len].copy_from_slice(&self.fast_buffer);
+                self.fast_buffer.clear();
+                Ok(len)
+            }
+            Some(inner) => unsafe {
+                if !self.fast_buffer.is_empty() {
+                    limcode_encoder_write_bytes(
+                        inner,
+                        self.fast_buffer.as_ptr(),
+                        self.fast_buffer.len(),
+                    );
+                    self.fast_buffer.clear();
+                }
+
+                let mut size = 0usize;
+                let status =
+                    limcode_encoder_into_slice(inner, out.as_mut_ptr(), out.len(), &mut size);
+
+                if status == 0 {
+                    limcode_encoder_clear(inner);
+                    Ok(size)
+                } else {
+                    Err(size)
+                }
+            },
+        }
+    }
 }
 
 impl Drop for Encoder {
@@ -580,86 +1014,678 @@ impl Drop for Encoder {
     }
 }
 
-/// High-performance binary decoder with SIMD optimizations
-pub struct Decoder<'a> {
-    inner: *mut LimcodeDecoder,
-    _phantom: std::marker::PhantomData<&'a ()>,
+/// Sentinel byte appended after a `write_str` payload
+///
+/// `0xFF` can never appear in well-formed UTF-8 (RFC 3629 reserves it - it's not a valid
+/// ASCII, lead, or continuation byte), so a decoder that doesn't find it immediately after the
+/// expected length has detected stream desynchronization rather than silently reading garbage.
+const STR_SENTINEL: u8 = 0xFF;
+
+/// `write_f32_compact`/`write_f64_compact` tag: the value is exact positive zero
+const FLOAT_TAG_ZERO: u8 = 0;
+/// `write_f32_compact`/`write_f64_compact` tag: the value round-trips exactly through a
+/// zigzag-encoded `i64`, so only the integer (not the raw float bits) is written
+const FLOAT_TAG_INT: u8 = 1;
+/// `write_f32_compact`/`write_f64_compact` tag: none of the compact cases apply - the raw bits
+/// follow unmodified
+const FLOAT_TAG_FULL: u8 = 2;
+
+/// `true` if `value` is finite, integer-valued, and round-trips exactly through `i64`, in which
+/// case the returned integer reconstructs `value` bit-for-bit via `as f32`
+fn compact_int_for_f32(value: f32) -> Option<i64> {
+    if !value.is_finite() || value.fract() != 0.0 {
+        return None;
+    }
+    let as_int = value as i64;
+    // Compare bit patterns rather than `==` so `-0.0` (which compares equal to `0.0` but has a
+    // different sign bit) isn't wrongly folded into the integer path and silently loses its sign.
+    if (as_int as f32).to_bits() == value.to_bits() {
+        Some(as_int)
+    } else {
+        None
+    }
 }
 
-impl<'a> Decoder<'a> {
-    /// Create a new decoder from bytes
-    pub fn new(data: &'a [u8]) -> Self {
-        unsafe {
-            let inner = limcode_decoder_new(data.as_ptr(), data.len());
-            assert!(!inner.is_null(), "Failed to create decoder");
-            Self {
-                inner,
-                _phantom: std::marker::PhantomData,
-            }
-        }
+/// Like `compact_int_for_f32`, for f64
+fn compact_int_for_f64(value: f64) -> Option<i64> {
+    if !value.is_finite() || value.fract() != 0.0 {
+        return None;
+    }
+    let as_int = value as i64;
+    if (as_int as f64).to_bits() == value.to_bits() {
+        Some(as_int)
+    } else {
+        None
     }
+}
 
-    /// Read a u8
-    pub fn read_u8(&mut self) -> Result<u8, &'static str> {
-        unsafe {
-            let mut val = 0u8;
-            if limcode_decoder_read_u8(self.inner, &mut val) != 0 {
-                return Err("Failed to read u8");
-            }
-            Ok(val)
+/// Zigzag-encode a signed integer so small magnitudes in either direction stay compact under
+/// `write_varint` - same mapping as `Encoder::write_svarint`, duplicated here because the
+/// float tag byte already goes through the FFI-backed `write_u8`/`write_varint`, and mixing
+/// those with the `fast_buffer`-only `write_svarint` would hit the ordering hazard documented
+/// on `codec.rs`
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of `zigzag_encode`
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Size of `FileEncoder`'s fixed staging buffer
+const FILE_ENCODER_BUFFER_SIZE: usize = 8192;
+
+/// Streaming encoder that flushes to any `std::io::Write` with bounded memory use
+///
+/// `Encoder` materializes the entire payload in memory before `finish()`, which is wasteful
+/// for multi-gigabyte payloads. `FileEncoder` instead owns a fixed-size staging buffer (8 KiB)
+/// and a filled-length cursor, flushing to the underlying writer (typically a
+/// `BufWriter<File>`) whenever the buffer fills up, so memory use stays bounded regardless of
+/// payload size.
+pub struct FileEncoder<W: Write> {
+    writer: W,
+    buffer: [u8; FILE_ENCODER_BUFFER_SIZE],
+    filled: usize,
+    total_written: u64,
+}
+
+impl<W: Write> FileEncoder<W> {
+    /// Wrap a writer in a streaming encoder
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            buffer: [0u8; FILE_ENCODER_BUFFER_SIZE],
+            filled: 0,
+            total_written: 0,
         }
     }
 
-    /// Read a u16 (little-endian)
-    pub fn read_u16(&mut self) -> Result<u16, &'static str> {
-        unsafe {
-            let mut val = 0u16;
-            if limcode_decoder_read_u16(self.inner, &mut val) != 0 {
-                return Err("Failed to read u16");
-            }
-            Ok(val)
+    /// Flush any buffered bytes to the underlying writer
+    fn flush_buffer(&mut self) -> std::io::Result<()> {
+        if self.filled > 0 {
+            self.writer.write_all(&self.buffer[..self.filled])?;
+            self.filled = 0;
         }
+        Ok(())
     }
 
-    /// Read a u32 (little-endian)
-    pub fn read_u32(&mut self) -> Result<u32, &'static str> {
-        unsafe {
-            let mut val = 0u32;
-            if limcode_decoder_read_u32(self.inner, &mut val) != 0 {
-                return Err("Failed to read u32");
-            }
-            Ok(val)
+    /// Guarantee at least `N` bytes of contiguous room in the staging buffer (flushing first
+    /// if needed), hand the closure a fixed-size destination, and advance the cursor by
+    /// whatever count it returns.
+    ///
+    /// The fixed-size `&mut [u8; N]` destination lets the compiler emit a single unbounded
+    /// store for the closure body instead of a bounds-checked slice write, the same trick
+    /// `Encoder`'s fast-buffer writes rely on.
+    pub fn write_with<const N: usize, F>(&mut self, f: F) -> std::io::Result<()>
+    where
+        F: FnOnce(&mut [u8; N]) -> usize,
+    {
+        assert!(
+            N <= FILE_ENCODER_BUFFER_SIZE,
+            "write_with chunk does not fit in the staging buffer"
+        );
+
+        if self.filled + N > self.buffer.len() {
+            self.flush_buffer()?;
         }
+
+        let dest: &mut [u8; N] = (&mut self.buffer[self.filled..self.filled + N])
+            .try_into()
+            .expect("slice has exactly N bytes");
+        let written = f(dest);
+        self.filled += written;
+        self.total_written += written as u64;
+        Ok(())
     }
 
-    /// Read a u64 (little-endian)
-    pub fn read_u64(&mut self) -> Result<u64, &'static str> {
-        unsafe {
-            let mut val = 0u64;
-            if limcode_decoder_read_u64(self.inner, &mut val) != 0 {
-                return Err("Failed to read u64");
-            }
-            Ok(val)
-        }
+    /// Write a u8
+    pub fn write_u8(&mut self, value: u8) -> std::io::Result<()> {
+        self.write_with::<1, _>(|buf| {
+            buf[0] = value;
+            1
+        })
     }
 
-    /// Read raw bytes
-    ///
-    /// IMPORTANT: Due to ultra-aggressive C++ compiler optimizations, memcpy operations
-    /// >48KB can crash. We use adaptive chunking for safety.
-    pub fn read_bytes(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
-        // Adaptive chunking strategy balancing safety vs FFI overhead
-        let chunk_size = match out.len() {
-            0..=4096 => out.len(),        // Tiny: no chunking
-            4097..=65536 => 16 * 1024,    // Small: 16KB chunks
-            65537..=1048576 => 32 * 1024, // Medium: 32KB chunks
-            _ => 48 * 1024,               // Large: 48KB chunks (maximum safe)
-        };
+    /// Write a u16 (little-endian)
+    pub fn write_u16(&mut self, value: u16) -> std::io::Result<()> {
+        self.write_with::<2, _>(|buf| {
+            buf.copy_from_slice(&value.to_le_bytes());
+            2
+        })
+    }
 
-        if out.len() <= chunk_size {
+    /// Write a u32 (little-endian)
+    pub fn write_u32(&mut self, value: u32) -> std::io::Result<()> {
+        self.write_with::<4, _>(|buf| {
+            buf.copy_from_slice(&value.to_le_bytes());
+            4
+        })
+    }
+
+    /// Write a u64 (little-endian)
+    pub fn write_u64(&mut self, value: u64) -> std::io::Result<()> {
+        self.write_with::<8, _>(|buf| {
+            buf.copy_from_slice(&value.to_le_bytes());
+            8
+        })
+    }
+
+    /// Write an unsigned LEB128 varint, routed through `write_with` like the fixed-width
+    /// helpers above so small values never touch the large-block path
+    pub fn write_varint(&mut self, value: u64) -> std::io::Result<()> {
+        self.write_with::<10, _>(|buf| {
+            let mut value = value;
+            let mut written = 0;
+            loop {
+                let mut byte = (value & 0x7f) as u8;
+                value >>= 7;
+                if value != 0 {
+                    byte |= 0x80;
+                }
+                buf[written] = byte;
+                written += 1;
+                if value == 0 {
+                    break;
+                }
+            }
+            written
+        })
+    }
+
+    /// Signed LEB128 varint write (zigzag-encoded), matching `Encoder::write_svarint`
+    pub fn write_svarint(&mut self, value: i64) -> std::io::Result<()> {
+        let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        self.write_varint(zigzag)
+    }
+
+    /// Write raw bytes
+    ///
+    /// Blocks that fit in the remaining staging space are copied in directly. Once a block
+    /// would overflow the buffer, it flushes and streams the data straight to the writer in
+    /// buffer-sized chunks, reusing `fast_nt_memcpy`'s non-temporal bulk copy for each chunk
+    /// instead of growing the fixed-size staging buffer to fit.
+    pub fn write_bytes(&mut self, data: &[u8]) -> std::io::Result<()> {
+        if self.filled + data.len() <= self.buffer.len() {
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    data.as_ptr(),
+                    self.buffer.as_mut_ptr().add(self.filled),
+                    data.len(),
+                );
+            }
+            self.filled += data.len();
+        } else {
+            self.flush_buffer()?;
+            for chunk in data.chunks(self.buffer.len()) {
+                unsafe {
+                    fast_nt_memcpy(self.buffer.as_mut_ptr(), chunk.as_ptr(), chunk.len());
+                }
+                self.writer.write_all(&self.buffer[..chunk.len()])?;
+            }
+        }
+
+        self.total_written += data.len() as u64;
+        Ok(())
+    }
+
+    /// Flush any remaining buffered bytes and return the total number of bytes written
+    pub fn finish(mut self) -> std::io::Result<u64> {
+        self.flush_buffer()?;
+        self.writer.flush()?;
+        Ok(self.total_written)
+    }
+}
+
+/// `FileEncoder` also implements `Write` directly, so it can stand in anywhere a plain
+/// `std::io::Write` sink is expected - e.g. wrapped by a compressing or hashing writer upstream
+/// of it, or passed to `std::io::copy` to stream raw payload bytes through without a round trip
+/// through `write_bytes`'s caller-facing API.
+impl<W: Write> Write for FileEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_bytes(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flush_buffer()?;
+        self.writer.flush()
+    }
+}
+
+/// Streaming decoder that pulls incrementally from any `std::io::Read` source - the read-side
+/// counterpart to `FileEncoder`
+///
+/// `Decoder` requires the entire payload buffered up front (`Decoder::new(&bytes)` borrows a
+/// slice), which doesn't fit a network socket, an unbounded file, or a decompressor's output.
+/// `FileDecoder` instead reads only as many bytes as each value actually needs directly from the
+/// underlying reader, with no internal buffering beyond a value's own fixed-size stack buffer,
+/// and reports a stream that ends mid-value as `DecodeError::UnexpectedEnd` rather than a bare
+/// `std::io::Error`. The slice-backed `Decoder` remains the zero-copy fast path when the payload is
+/// already fully in memory. Wrap a `BufReader<File>` or a `zstd`/`flate2` stream in this to decode
+/// accounts straight off a compressed snapshot with bounded memory instead of materializing the
+/// whole block up front. Both this and `Decoder` implement the shared `Source` trait, so generic
+/// decoding code can take either.
+pub struct FileDecoder<R: Read> {
+    reader: R,
+    total_read: u64,
+}
+
+impl<R: Read> FileDecoder<R> {
+    /// Wrap a reader in a streaming decoder
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            total_read: 0,
+        }
+    }
+
+    /// Fill `buf` completely from the underlying stream, or report exactly how far it got
+    ///
+    /// Unlike `Read::read_exact`, a short read is reported as `DecodeError::UnexpectedEnd`
+    /// (`remaining` holds however many bytes actually arrived before the stream ended) instead
+    /// of an opaque `std::io::Error`, and `std::io::ErrorKind::Interrupted` is retried transparently.
+    pub fn read_bytes(&mut self, buf: &mut [u8]) -> Result<(), DecodeError> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match self.reader.read(&mut buf[filled..]) {
+                Ok(0) => {
+                    return Err(DecodeError::UnexpectedEnd {
+                        needed: buf.len(),
+                        remaining: filled,
+                        offset: self.total_read as usize,
+                    });
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(DecodeError::Io(e.kind())),
+            }
+        }
+        self.total_read += buf.len() as u64;
+        Ok(())
+    }
+
+    /// Read a u8
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        let mut buf = [0u8; 1];
+        self.read_bytes(&mut buf)?;
+        Ok(buf[0])
+    }
+
+    /// Read a u16 (little-endian)
+    pub fn read_u16(&mut self) -> Result<u16, DecodeError> {
+        let mut buf = [0u8; 2];
+        self.read_bytes(&mut buf)?;
+        Ok(u16::from_le_bytes(buf))
+    }
+
+    /// Read a u32 (little-endian)
+    pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        let mut buf = [0u8; 4];
+        self.read_bytes(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    /// Read a u64 (little-endian)
+    pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        let mut buf = [0u8; 8];
+        self.read_bytes(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// Read an unsigned LEB128 varint one byte at a time, matching `Decoder::read_varint_fast`'s
+    /// encoding and maximum length
+    pub fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        const MAX_ENCODED_LEN: usize = 10;
+        let mut result = 0u64;
+        let mut shift = 0u32;
+        for _ in 0..MAX_ENCODED_LEN {
+            let offset = self.total_read as usize;
+            let byte = self.read_u8()?;
+            if shift >= 64 || (shift == 63 && (byte & 0x7f) > 1) {
+                return Err(DecodeError::InvalidVarint { offset });
+            }
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+        Err(DecodeError::InvalidVarint {
+            offset: self.total_read as usize,
+        })
+    }
+
+    /// Read a Solana short_vec compact-u16 length, one byte at a time from the stream
+    ///
+    /// Mirrors `read_shortvec_len`'s accumulate-7-bits-per-byte loop and non-canonical-encoding
+    /// rejection (more than 3 bytes, a value over `u16::MAX`, or a needless trailing
+    /// continuation byte), but reads through `read_u8` instead of indexing a slice, since a
+    /// stream has no bytes to look ahead into until they're actually read.
+    pub fn read_shortu16(&mut self) -> Result<u16, DecodeError> {
+        let start = self.total_read as usize;
+        let mut result: u64 = 0;
+        let mut shift = 0u32;
+
+        for i in 0..3 {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                if result > SHORTVEC_MAX_LEN {
+                    return Err(DecodeError::InvalidVarint { offset: start });
+                }
+                let consumed = i + 1;
+                let mut canonical = Vec::with_capacity(consumed);
+                write_shortvec_len(result as usize, &mut canonical)
+                    .expect("just checked result <= SHORTVEC_MAX_LEN");
+                if canonical.len() != consumed {
+                    return Err(DecodeError::InvalidVarint { offset: start });
+                }
+                return Ok(result as u16);
+            }
+            shift += 7;
+        }
+        Err(DecodeError::InvalidVarint { offset: start })
+    }
+
+    /// Total bytes successfully read from the stream so far
+    pub fn total_read(&self) -> u64 {
+        self.total_read
+    }
+}
+
+/// `FileDecoder` also implements `Read` directly, passing reads straight through to the
+/// underlying source while keeping `total_read` in sync - the read-side counterpart to
+/// `FileEncoder`'s `Write` impl. This is a plain pass-through (short reads and `Ok(0)` at EOF are
+/// both valid per `Read`'s contract), unlike `read_bytes`, which always fills its buffer
+/// completely or reports `DecodeError::UnexpectedEnd`.
+impl<R: Read> Read for FileDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.total_read += n as u64;
+        Ok(n)
+    }
+}
+
+/// Common read surface shared by the slice-backed `Decoder` and the streaming `FileDecoder`
+///
+/// Generic deserialization code can take `impl Source` (or `S: Source`) instead of committing to
+/// one concrete decoder, so the same function works whether the caller already has the full
+/// payload in memory or is pulling it incrementally off a `BufReader<File>` or decompressor -
+/// e.g. the account-by-account walk in `snapshot::stream_snapshot_full`.
+pub trait Source {
+    /// Read a u8
+    fn read_u8(&mut self) -> Result<u8, DecodeError>;
+    /// Read a u16 (little-endian)
+    fn read_u16(&mut self) -> Result<u16, DecodeError>;
+    /// Read a u32 (little-endian)
+    fn read_u32(&mut self) -> Result<u32, DecodeError>;
+    /// Read a u64 (little-endian)
+    fn read_u64(&mut self) -> Result<u64, DecodeError>;
+    /// Fill `out` completely
+    fn read_bytes(&mut self, out: &mut [u8]) -> Result<(), DecodeError>;
+    /// Read an unsigned LEB128 varint
+    fn read_varint(&mut self) -> Result<u64, DecodeError>;
+    /// Read a Solana short_vec compact-u16 length
+    fn read_shortu16(&mut self) -> Result<u16, DecodeError>;
+}
+
+impl<R: Read> Source for FileDecoder<R> {
+    fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        FileDecoder::read_u8(self)
+    }
+    fn read_u16(&mut self) -> Result<u16, DecodeError> {
+        FileDecoder::read_u16(self)
+    }
+    fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        FileDecoder::read_u32(self)
+    }
+    fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        FileDecoder::read_u64(self)
+    }
+    fn read_bytes(&mut self, out: &mut [u8]) -> Result<(), DecodeError> {
+        FileDecoder::read_bytes(self, out)
+    }
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        FileDecoder::read_varint(self)
+    }
+    fn read_shortu16(&mut self) -> Result<u16, DecodeError> {
+        FileDecoder::read_shortu16(self)
+    }
+}
+
+/// A structured decode failure, carrying the buffer offset where it occurred
+///
+/// Replaces the bare `&'static str` errors previously returned by every `Decoder` reader, which
+/// told a caller *that* something went wrong but not *what* or *where* in a corrupt/truncated
+/// stream. `From<DecodeError> for &'static str` is provided so existing call sites using `?`
+/// into a `Result<_, &'static str>` (e.g. the `Encode`/`Decode` impls in `codec.rs`) keep
+/// compiling unchanged, at the cost of losing the structured detail on that path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// A read needed more bytes than remained in the buffer
+    UnexpectedEnd {
+        needed: usize,
+        remaining: usize,
+        offset: usize,
+    },
+    /// A length-prefixed read would exceed the decoder's `with_limit` budget
+    LimitExceeded {
+        requested: usize,
+        limit: usize,
+        offset: usize,
+    },
+    /// A LEB128 varint did not terminate within the maximum encoded length, or its value
+    /// overflowed the target integer width
+    InvalidVarint { offset: usize },
+    /// A length prefix could not be represented as a `usize` on this target
+    LengthOverflow { offset: usize },
+    /// The underlying `std::io::Read` source (see `FileDecoder`) returned an error other than a
+    /// short read, which is reported as `UnexpectedEnd` instead
+    Io(std::io::ErrorKind),
+    /// Catch-all for lower-level failures (e.g. the underlying FFI call itself failing, or a
+    /// malformed tag/sentinel byte) that don't carry their own offset
+    Other(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd {
+                needed,
+                remaining,
+                offset,
+            } => write!(
+                f,
+                "unexpected end of buffer at offset {offset}: needed {needed} bytes, {remaining} remaining"
+            ),
+            DecodeError::LimitExceeded {
+                requested,
+                limit,
+                offset,
+            } => write!(
+                f,
+                "decoder byte limit exceeded at offset {offset}: requested {requested} bytes, {limit} remaining in budget"
+            ),
+            DecodeError::InvalidVarint { offset } => {
+                write!(f, "invalid varint encoding at offset {offset}")
+            }
+            DecodeError::LengthOverflow { offset } => {
+                write!(f, "length prefix overflows usize at offset {offset}")
+            }
+            DecodeError::Io(kind) => write!(f, "I/O error while decoding: {kind}"),
+            DecodeError::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<DecodeError> for &'static str {
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::UnexpectedEnd { .. } => "unexpected end of buffer",
+            DecodeError::LimitExceeded { .. } => "requested read exceeds decoder byte limit",
+            DecodeError::InvalidVarint { .. } => "invalid varint encoding",
+            DecodeError::LengthOverflow { .. } => "length prefix overflows usize",
+            DecodeError::Io(_) => "I/O error while decoding",
+            DecodeError::Other(msg) => msg,
+        }
+    }
+}
+
+/// High-performance binary decoder with SIMD optimizations
+pub struct Decoder<'a> {
+    inner: *mut LimcodeDecoder,
+    // Retained so the pure-Rust varint fast path (see `read_varint_fast`/`read_svarint`) can
+    // decode directly from the original bytes instead of crossing the FFI boundary byte-by-byte.
+    data: &'a [u8],
+    // Byte order for read_u16/read_u32/read_u64; see `with_endian`
+    endian: Endian,
+    // Remaining allocation budget in bytes; see `with_limit`. `None` means unbounded.
+    limit: Option<usize>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a new decoder from bytes
+    pub fn new(data: &'a [u8]) -> Self {
+        selected_simd_level();
+        unsafe {
+            let inner = limcode_decoder_new(data.as_ptr(), data.len());
+            assert!(!inner.is_null(), "Failed to create decoder");
+            Self {
+                inner,
+                data,
+                endian: Endian::default(),
+                limit: None,
+            }
+        }
+    }
+
+    /// Select the byte order `read_u16`/`read_u32`/`read_u64` use for this decoder - must match
+    /// whatever `Endian` the producing `Encoder` was constructed with
+    pub fn with_endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Cap the total bytes this decoder will allocate for length-prefixed reads
+    /// (`read_vec_bincode`, `read_bytes`) to `max_bytes`, decremented as bytes are consumed
+    ///
+    /// Without a limit, a corrupt or malicious length prefix can force a multi-gigabyte
+    /// allocation before any of the claimed bytes are actually validated against the input.
+    /// `with_limit` bounds that up front; exceeding either the limit or the bytes actually
+    /// remaining in the input returns an error instead of allocating.
+    pub fn with_limit(mut self, max_bytes: usize) -> Self {
+        self.limit = Some(max_bytes);
+        self
+    }
+
+    /// Verify a length-prefixed read of `n` bytes is allowed before any buffer for it is
+    /// allocated: it must fit both the bytes actually left in the input (`remaining()`) and,
+    /// if configured, the remaining `with_limit` budget
+    fn check_budget(&self, n: usize) -> Result<(), DecodeError> {
+        let remaining = self.remaining();
+        let offset = self.offset();
+        if n > remaining {
+            return Err(DecodeError::UnexpectedEnd {
+                needed: n,
+                remaining,
+                offset,
+            });
+        }
+        if let Some(limit) = self.limit {
+            if n > limit {
+                return Err(DecodeError::LimitExceeded {
+                    requested: n,
+                    limit,
+                    offset,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Current read position: how many bytes of `data` have already been consumed
+    fn offset(&self) -> usize {
+        self.data.len() - self.remaining()
+    }
+
+    /// Read a u8
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        unsafe {
+            let mut val = 0u8;
+            if limcode_decoder_read_u8(self.inner, &mut val) != 0 {
+                return Err(DecodeError::Other("Failed to read u8"));
+            }
+            Ok(val)
+        }
+    }
+
+    /// Read a u16, honoring the decoder's configured `Endian` (`Little` by default)
+    pub fn read_u16(&mut self) -> Result<u16, DecodeError> {
+        unsafe {
+            let mut val = 0u16;
+            if limcode_decoder_read_u16(self.inner, &mut val) != 0 {
+                return Err(DecodeError::Other("Failed to read u16"));
+            }
+            Ok(match self.endian {
+                Endian::Little => u16::from_le(val),
+                Endian::Big => u16::from_be(val),
+            })
+        }
+    }
+
+    /// Read a u32, honoring the decoder's configured `Endian` (`Little` by default)
+    pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        unsafe {
+            let mut val = 0u32;
+            if limcode_decoder_read_u32(self.inner, &mut val) != 0 {
+                return Err(DecodeError::Other("Failed to read u32"));
+            }
+            Ok(match self.endian {
+                Endian::Little => u32::from_le(val),
+                Endian::Big => u32::from_be(val),
+            })
+        }
+    }
+
+    /// Read a u64, honoring the decoder's configured `Endian` (`Little` by default)
+    pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        unsafe {
+            let mut val = 0u64;
+            if limcode_decoder_read_u64(self.inner, &mut val) != 0 {
+                return Err(DecodeError::Other("Failed to read u64"));
+            }
+            Ok(match self.endian {
+                Endian::Little => u64::from_le(val),
+                Endian::Big => u64::from_be(val),
+            })
+        }
+    }
+
+    /// Read raw bytes
+    ///
+    /// IMPORTANT: Due to ultra-aggressive C++ compiler optimizations, memcpy operations
+    /// >48KB can crash. We use adaptive chunking for safety.
+    pub fn read_bytes(&mut self, out: &mut [u8]) -> Result<(), DecodeError> {
+        self.check_budget(out.len())?;
+        if let Some(limit) = self.limit {
+            self.limit = Some(limit - out.len());
+        }
+
+        // Adaptive chunking strategy balancing safety vs FFI overhead
+        let chunk_size = match out.len() {
+            0..=4096 => out.len(),        // Tiny: no chunking
+            4097..=65536 => 16 * 1024,    // Small: 16KB chunks
+            65537..=1048576 => 32 * 1024, // Medium: 32KB chunks
+            _ => 48 * 1024,               // Large: 48KB chunks (maximum safe)
+        };
+
+        if out.len() <= chunk_size {
             unsafe {
                 if limcode_decoder_read_bytes(self.inner, out.as_mut_ptr(), out.len()) != 0 {
-                    return Err("Failed to read bytes");
+                    return Err(DecodeError::Other("Failed to read bytes"));
                 }
             }
         } else {
@@ -667,7 +1693,7 @@ impl<'a> Decoder<'a> {
                 unsafe {
                     if limcode_decoder_read_bytes(self.inner, chunk.as_mut_ptr(), chunk.len()) != 0
                     {
-                        return Err("Failed to read bytes");
+                        return Err(DecodeError::Other("Failed to read bytes"));
                     }
                 }
             }
@@ -675,23 +1701,199 @@ impl<'a> Decoder<'a> {
         Ok(())
     }
 
+    /// Read `out.len() * 2` hex-ASCII bytes written by `Encoder::write_hex`, decoding them into
+    /// `out`
+    ///
+    /// Errors with `DecodeError::Other` if the stream doesn't have a hex digit
+    /// (`0-9`, `a-f`, `A-F`) at every position, rather than silently masking off invalid
+    /// nibbles. Pairs with `Encoder::write_hex`/`to_hex`.
+    pub fn read_hex(&mut self, out: &mut [u8]) -> Result<(), DecodeError> {
+        let mut hex = vec![0u8; out.len() * 2];
+        self.read_bytes(&mut hex)?;
+        deserializer::hex_decode_into(&hex, out)
+            .map_err(|_| DecodeError::Other("Invalid hex digit in read_hex"))
+    }
+
     /// Read a varint (LEB128)
-    pub fn read_varint(&mut self) -> Result<u64, &'static str> {
+    pub fn read_varint(&mut self) -> Result<u64, DecodeError> {
         unsafe {
             let mut val = 0u64;
             if limcode_decoder_read_varint(self.inner, &mut val) != 0 {
-                return Err("Failed to read varint");
+                return Err(DecodeError::Other("Failed to read varint"));
             }
             Ok(val)
         }
     }
 
+    /// Pure-Rust unsigned LEB128 varint read
+    ///
+    /// The C++ decoder owns the authoritative read position, so this decodes straight out of
+    /// the original `data` slice at the decoder's current offset (`data.len() - remaining()`)
+    /// in pure Rust, then makes a single `read_bytes` call to advance the C++ side by the bytes
+    /// actually consumed - keeping both in sync if callers interleave this with FFI-backed
+    /// reads, while still avoiding a C++ round trip for the decode loop itself. Rejects
+    /// encodings longer than `MAX_ENCODED_LEN` (10 bytes for `u64`) or with an overflowing
+    /// shift.
+    pub fn read_varint_fast(&mut self) -> Result<u64, DecodeError> {
+        const MAX_ENCODED_LEN: usize = 10;
+        let start = self.offset();
+        let slice = &self.data[start..];
+
+        let mut result: u64 = 0;
+        let mut shift: u32 = 0;
+        let mut consumed = 0usize;
+
+        loop {
+            if consumed >= MAX_ENCODED_LEN {
+                return Err(DecodeError::InvalidVarint { offset: start });
+            }
+            let byte = *slice
+                .get(consumed)
+                .ok_or(DecodeError::UnexpectedEnd {
+                    needed: consumed + 1,
+                    remaining: slice.len(),
+                    offset: start,
+                })?;
+            consumed += 1;
+
+            if shift >= 64 || (shift == 63 && (byte & 0x7f) > 1) {
+                return Err(DecodeError::InvalidVarint { offset: start });
+            }
+
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+
+        let mut discard = [0u8; MAX_ENCODED_LEN];
+        self.read_bytes(&mut discard[..consumed])?;
+        Ok(result)
+    }
+
+    /// Read a Solana short_vec compact-u16 length written by `Encoder::write_shortu16`
+    ///
+    /// Delegates to the same `read_shortvec_len` that backs `deserialize_shortvec`, which
+    /// accumulates 7 bits per byte and rejects non-minimal encodings (more than 3 bytes, a
+    /// value over `u16::MAX`, or a needless trailing continuation byte) the same way Solana's
+    /// validators do - otherwise a malformed transaction could round-trip through two different
+    /// byte encodings of the same length. Any rejection (including running out of bytes) is
+    /// reported as `InvalidVarint`, since `read_shortvec_len` doesn't distinguish the two.
+    pub fn read_shortu16(&mut self) -> Result<u16, DecodeError> {
+        let start = self.offset();
+        let (value, consumed) = read_shortvec_len(&self.data[start..])
+            .map_err(|_| DecodeError::InvalidVarint { offset: start })?;
+
+        let mut discard = [0u8; 3];
+        self.read_bytes(&mut discard[..consumed])?;
+        Ok(value as u16)
+    }
+
+    /// Pure-Rust signed LEB128 varint read (zigzag-decoded, see `read_varint_fast`)
+    pub fn read_svarint(&mut self) -> Result<i64, DecodeError> {
+        let u = self.read_varint_fast()?;
+        Ok(((u >> 1) as i64) ^ -((u & 1) as i64))
+    }
+
+    /// Read a signed varint written by `write_varint_signed`
+    ///
+    /// Decodes via `read_varint_fast` (which already rejects encodings longer than 10 bytes and
+    /// has no FFI-ordering hazard), then reverses the zigzag mapping: `n = (z >> 1) ^ -(z & 1)`.
+    pub fn read_varint_signed(&mut self) -> Result<i64, DecodeError> {
+        let z = self.read_varint_fast()?;
+        Ok(((z >> 1) as i64) ^ -((z & 1) as i64))
+    }
+
+    /// Read the length-prefixed span and trailing `STR_SENTINEL` written by `Encoder::write_str`,
+    /// returning the raw byte span (still borrowed from the original input) with the read
+    /// cursor already advanced past it
+    fn read_str_span(&mut self) -> Result<&'a [u8], DecodeError> {
+        let len = self.read_varint_fast()? as usize;
+        let pos = self.offset();
+
+        let bytes = self.data.get(pos..pos + len).ok_or(DecodeError::UnexpectedEnd {
+            needed: len,
+            remaining: self.data.len() - pos,
+            offset: pos,
+        })?;
+        let sentinel = *self.data.get(pos + len).ok_or(DecodeError::UnexpectedEnd {
+            needed: 1,
+            remaining: 0,
+            offset: pos + len,
+        })?;
+        if sentinel != STR_SENTINEL {
+            return Err(DecodeError::Other(
+                "string stream desynchronized: missing sentinel byte",
+            ));
+        }
+
+        // Advance the C++ decoder's cursor past the bytes + sentinel we just parsed in Rust.
+        let mut discard = vec![0u8; len + 1];
+        self.read_bytes(&mut discard)?;
+
+        Ok(bytes)
+    }
+
+    /// Read a string written by `write_str`, borrowing directly from the input buffer
+    /// (zero-copy) and skipping UTF-8 validation on the hot path
+    ///
+    /// # Safety note
+    /// Uses `from_utf8_unchecked` internally - only call this on input you trust to be
+    /// well-formed (e.g. round-tripping your own `write_str` output). The `STR_SENTINEL` check
+    /// still catches stream desynchronization, but it cannot catch a valid-length span of
+    /// invalid UTF-8; use `read_str_validated` for untrusted input.
+    pub fn read_str(&mut self) -> Result<&'a str, DecodeError> {
+        let bytes = self.read_str_span()?;
+        Ok(unsafe { std::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Like `read_str`, but validates UTF-8 instead of trusting the caller
+    pub fn read_str_validated(&mut self) -> Result<&'a str, DecodeError> {
+        let bytes = self.read_str_span()?;
+        std::str::from_utf8(bytes).map_err(|_| DecodeError::Other("invalid UTF-8 in decoded string"))
+    }
+
+    /// Read a raw f32 written by `write_f32`
+    pub fn read_f32(&mut self) -> Result<f32, DecodeError> {
+        Ok(f32::from_bits(self.read_u32()?))
+    }
+
+    /// Read an f32 written by `write_f32_compact`, reconstructing the exact bit pattern
+    pub fn read_f32_compact(&mut self) -> Result<f32, DecodeError> {
+        match self.read_u8()? {
+            FLOAT_TAG_ZERO => Ok(0.0),
+            FLOAT_TAG_INT => Ok(zigzag_decode(self.read_varint_fast()?) as f32),
+            FLOAT_TAG_FULL => Ok(f32::from_bits(self.read_u32()?)),
+            _ => Err(DecodeError::Other("invalid compact f32 tag")),
+        }
+    }
+
+    /// Read a raw f64 written by `write_f64`
+    pub fn read_f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_bits(self.read_u64()?))
+    }
+
+    /// Read an f64 written by `write_f64_compact`, reconstructing the exact bit pattern
+    pub fn read_f64_compact(&mut self) -> Result<f64, DecodeError> {
+        match self.read_u8()? {
+            FLOAT_TAG_ZERO => Ok(0.0),
+            FLOAT_TAG_INT => Ok(zigzag_decode(self.read_varint_fast()?) as f64),
+            FLOAT_TAG_FULL => Ok(f64::from_bits(self.read_u64()?)),
+            _ => Err(DecodeError::Other("invalid compact f64 tag")),
+        }
+    }
+
     /// Read Vec<u8> with bincode-compatible format (u64 length prefix + data)
     /// This matches bincode's default deserialization for Vec<u8>
-    pub fn read_vec_bincode(&mut self) -> Result<Vec<u8>, &'static str> {
+    pub fn read_vec_bincode(&mut self) -> Result<Vec<u8>, DecodeError> {
         // Read u64 length prefix
         let len = self.read_u64()? as usize;
 
+        // Validate before allocating: an attacker-controlled length must not force a large
+        // allocation before it's been checked against the input and `with_limit` budget.
+        self.check_budget(len)?;
+
         // Read data
         let mut data = vec![0u8; len];
         self.read_bytes(&mut data)?;
@@ -699,12 +1901,99 @@ impl<'a> Decoder<'a> {
         Ok(data)
     }
 
+    /// Read a length-prefixed array of fixed-size `Copy` elements lazily, without allocating an
+    /// intermediate `Vec<T>`
+    ///
+    /// Mirrors `read_vec_bincode`'s wire format (a `u64` element count followed by the elements'
+    /// raw bytes back to back), generalized from `u8` to any fixed-layout `Copy` `T` (plain
+    /// integers, `[u8; N]`, repr(C) structs, etc). The returned `BincodeVecIter` borrows
+    /// directly from the input and copies out one `T` per `next()` call, so streaming a large
+    /// on-disk array costs no more memory than the iterator itself. `check_budget` still rejects
+    /// a claimed length that would exceed the input or the `with_limit` budget before the span
+    /// is even borrowed.
+    pub fn decode_vec_iter<T: Copy>(&mut self) -> Result<BincodeVecIter<'a, T>, DecodeError> {
+        let len = self.read_u64()? as usize;
+        let elem_size = std::mem::size_of::<T>();
+        let offset = self.offset();
+        let byte_len = len
+            .checked_mul(elem_size)
+            .ok_or(DecodeError::LengthOverflow { offset })?;
+        self.check_budget(byte_len)?;
+
+        let pos = self.offset();
+        let bytes = &self.data[pos..pos + byte_len];
+
+        // Advance the C++ decoder's cursor past the element bytes the iterator now borrows,
+        // same discard-buffer approach `read_str_span` uses to stay in sync.
+        let mut discard = vec![0u8; byte_len];
+        self.read_bytes(&mut discard)?;
+
+        Ok(BincodeVecIter {
+            remaining: bytes,
+            len,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
     /// Get remaining bytes
     pub fn remaining(&self) -> usize {
         unsafe { limcode_decoder_remaining(self.inner) }
     }
 }
 
+/// Lazy iterator over a length-prefixed array of fixed-size elements, produced by
+/// `Decoder::decode_vec_iter`
+///
+/// Yields one `T` per call, copied directly out of the decoder's backing slice with no
+/// intermediate `Vec<T>`. Stops cleanly (returning `None`) if the declared count is reached or
+/// if fewer bytes remain than a full element - the latter can only happen if the input was
+/// truncated after `decode_vec_iter` already validated the claimed length against it.
+pub struct BincodeVecIter<'a, T> {
+    remaining: &'a [u8],
+    len: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: Copy> BincodeVecIter<'a, T> {
+    /// Number of elements not yet yielded
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no more elements to yield
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, T: Copy> Iterator for BincodeVecIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let elem_size = std::mem::size_of::<T>();
+        if self.remaining.len() < elem_size {
+            self.len = 0;
+            return None;
+        }
+        let (head, tail) = self.remaining.split_at(elem_size);
+        self.remaining = tail;
+        self.len -= 1;
+        // SAFETY: `decode_vec_iter` only hands out spans sized as an exact multiple of
+        // `size_of::<T>()`, and `T: Copy` rules out any drop glue/invalid-bit-pattern concerns
+        // beyond what the caller already accepts by choosing `T` for this format.
+        Some(unsafe { std::ptr::read_unaligned(head.as_ptr() as *const T) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: Copy> ExactSizeIterator for BincodeVecIter<'a, T> {}
+
 impl<'a> Drop for Decoder<'a> {
     fn drop(&mut self) {
         unsafe {
@@ -713,6 +2002,30 @@ impl<'a> Drop for Decoder<'a> {
     }
 }
 
+impl<'a> Source for Decoder<'a> {
+    fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        Decoder::read_u8(self)
+    }
+    fn read_u16(&mut self) -> Result<u16, DecodeError> {
+        Decoder::read_u16(self)
+    }
+    fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        Decoder::read_u32(self)
+    }
+    fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        Decoder::read_u64(self)
+    }
+    fn read_bytes(&mut self, out: &mut [u8]) -> Result<(), DecodeError> {
+        Decoder::read_bytes(self, out)
+    }
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        Decoder::read_varint_fast(self)
+    }
+    fn read_shortu16(&mut self) -> Result<u16, DecodeError> {
+        Decoder::read_shortu16(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -746,9 +2059,127 @@ mod tests {
     }
 
     #[test]
-    fn test_varint() {
-        let mut enc = Encoder::new();
-        enc.write_varint(127);
+    fn test_with_capacity_pre_allocates_fast_buffer_and_still_encodes_correctly() {
+        let mut enc = Encoder::with_capacity(64);
+        assert!(enc.fast_buffer.capacity() >= 64);
+        enc.write_u8(42);
+        enc.write_u32(567890);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u8().unwrap(), 42);
+        assert_eq!(dec.read_u32().unwrap(), 567890);
+    }
+
+    #[test]
+    fn test_reserve_exact_grows_fast_buffer_capacity() {
+        let mut enc = Encoder::new();
+        let before = enc.fast_buffer.capacity();
+        enc.reserve_exact(256);
+        assert!(enc.fast_buffer.capacity() >= before + 256);
+    }
+
+    #[test]
+    fn test_finish_into_matches_finish_and_reuses_the_same_encoder() {
+        let mut enc = Encoder::new();
+        enc.write_u8(1);
+        enc.write_u64(2);
+        let expected = {
+            let mut enc = Encoder::new();
+            enc.write_u8(1);
+            enc.write_u64(2);
+            enc.finish()
+        };
+
+        let mut out = Vec::new();
+        enc.finish_into(&mut out);
+        assert_eq!(out, expected);
+
+        // The encoder is reset, not consumed - a second round trip on the same instance
+        // produces the same bytes as a fresh encoder would.
+        enc.write_u8(3);
+        enc.write_u64(4);
+        let mut out2 = Vec::new();
+        enc.finish_into(&mut out2);
+
+        let mut dec = Decoder::new(&out2);
+        assert_eq!(dec.read_u8().unwrap(), 3);
+        assert_eq!(dec.read_u64().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_finish_into_round_trips_the_fast_buffer_only_path() {
+        // `write_varint_fast` never creates the C++ encoder, so this exercises `finish_into`'s
+        // `self.inner.is_none()` branch specifically.
+        let mut enc = Encoder::new();
+        enc.write_varint_fast(300);
+        let mut out = Vec::new();
+        enc.finish_into(&mut out);
+
+        let mut dec = Decoder::new(&out);
+        assert_eq!(dec.read_varint_fast().unwrap(), 300);
+
+        enc.write_varint_fast(9999);
+        let mut out2 = Vec::new();
+        enc.finish_into(&mut out2);
+        let mut dec2 = Decoder::new(&out2);
+        assert_eq!(dec2.read_varint_fast().unwrap(), 9999);
+    }
+
+    #[test]
+    fn test_finish_into_slice_matches_finish_and_reuses_the_same_encoder() {
+        let mut enc = Encoder::new();
+        enc.write_u8(1);
+        enc.write_u64(2);
+        let expected = {
+            let mut enc = Encoder::new();
+            enc.write_u8(1);
+            enc.write_u64(2);
+            enc.finish()
+        };
+
+        let mut out = vec![0u8; expected.len()];
+        let written = enc.finish_into_slice(&mut out).unwrap();
+        assert_eq!(written, expected.len());
+        assert_eq!(out, expected);
+
+        // The encoder is reset, not consumed - a second round trip on the same instance
+        // produces the same bytes as a fresh encoder would.
+        enc.write_u8(3);
+        enc.write_u64(4);
+        let mut out2 = vec![0u8; 9];
+        let written2 = enc.finish_into_slice(&mut out2).unwrap();
+
+        let mut dec = Decoder::new(&out2[..written2]);
+        assert_eq!(dec.read_u8().unwrap(), 3);
+        assert_eq!(dec.read_u64().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_finish_into_slice_reports_required_len_without_losing_data_when_too_small() {
+        let mut enc = Encoder::new();
+        enc.write_u8(1);
+        enc.write_u64(2);
+        let expected = {
+            let mut enc = Encoder::new();
+            enc.write_u8(1);
+            enc.write_u64(2);
+            enc.finish()
+        };
+
+        let mut too_small = vec![0u8; expected.len() - 1];
+        assert_eq!(enc.finish_into_slice(&mut too_small), Err(expected.len()));
+
+        // Nothing was lost: a correctly-sized buffer still gets the full payload.
+        let mut out = vec![0u8; expected.len()];
+        assert_eq!(enc.finish_into_slice(&mut out), Ok(expected.len()));
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_varint() {
+        let mut enc = Encoder::new();
+        enc.write_varint(127);
         enc.write_varint(16383);
         enc.write_varint(1048575);
         let bytes = enc.finish();
@@ -758,4 +2189,780 @@ mod tests {
         assert_eq!(dec.read_varint().unwrap(), 16383);
         assert_eq!(dec.read_varint().unwrap(), 1048575);
     }
+
+    #[test]
+    fn test_varint_fast_matches_ffi_path() {
+        let mut enc = Encoder::new();
+        enc.write_varint_fast(127);
+        enc.write_varint_fast(16383);
+        enc.write_varint_fast(1048575);
+        enc.write_varint_fast(u64::MAX);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_varint_fast().unwrap(), 127);
+        assert_eq!(dec.read_varint_fast().unwrap(), 16383);
+        assert_eq!(dec.read_varint_fast().unwrap(), 1048575);
+        assert_eq!(dec.read_varint_fast().unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_varint_fast_single_byte_is_compact() {
+        let mut enc = Encoder::new();
+        enc.write_varint_fast(42);
+        assert_eq!(enc.finish(), vec![42]);
+    }
+
+    #[test]
+    fn test_svarint_round_trips_positive_and_negative() {
+        let mut enc = Encoder::new();
+        for value in [0i64, 1, -1, 127, -127, i64::MAX, i64::MIN] {
+            enc.write_svarint(value);
+        }
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        for value in [0i64, 1, -1, 127, -127, i64::MAX, i64::MIN] {
+            assert_eq!(dec.read_svarint().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_varint_signed_round_trips_positive_and_negative() {
+        let mut enc = Encoder::new();
+        for value in [0i64, 1, -1, 127, -127, i64::MAX, i64::MIN] {
+            enc.write_varint_signed(value);
+        }
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        for value in [0i64, 1, -1, 127, -127, i64::MAX, i64::MIN] {
+            assert_eq!(dec.read_varint_signed().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_varint_signed_interleaves_safely_with_ffi_writes() {
+        // write_varint_signed goes through the FFI-backed write_varint, so it's safe to
+        // interleave with other FFI writes on the same Encoder (unlike write_svarint, which
+        // would desync against fast_buffer's flush-after-FFI ordering - see codec.rs).
+        let mut enc = Encoder::new();
+        enc.write_u32(7);
+        enc.write_varint_signed(-12345);
+        enc.write_u8(9);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        assert_eq!(dec.read_varint_signed().unwrap(), -12345);
+        assert_eq!(dec.read_u8().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_varint_fast_interleaves_with_ffi_reads() {
+        // Bytes written entirely through the FFI path so encode-side ordering is unaffected;
+        // this exercises `read_varint_fast` keeping its cursor in sync with interleaved
+        // FFI-backed reads on the decode side.
+        let mut enc = Encoder::new();
+        enc.write_u32(7);
+        enc.write_varint(300);
+        enc.write_u8(9);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        assert_eq!(dec.read_varint_fast().unwrap(), 300);
+        assert_eq!(dec.read_u8().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_shortvec_empty() {
+        let encoded = serialize_shortvec(&[]).unwrap();
+        assert_eq!(encoded, vec![0]);
+        assert_eq!(deserialize_shortvec(&encoded).unwrap(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn test_shortvec_single_element() {
+        let data = vec![42u8];
+        let encoded = serialize_shortvec(&data).unwrap();
+        assert_eq!(encoded, vec![1, 42]);
+        assert_eq!(deserialize_shortvec(&encoded).unwrap(), &data[..]);
+    }
+
+    #[test]
+    fn test_shortvec_multi_byte_length() {
+        // 300 requires two bytes: 0xAC (continuation bit set) then 0x02
+        let data = vec![7u8; 300];
+        let encoded = serialize_shortvec(&data).unwrap();
+        assert_eq!(&encoded[..2], &[0xAC, 0x02]);
+        assert_eq!(deserialize_shortvec(&encoded).unwrap(), &data[..]);
+
+        // Compare against the fixed-width bincode path to show the header is smaller
+        let bincode_encoded = serialize_bincode(&data);
+        assert!(encoded.len() < bincode_encoded.len());
+        assert_eq!(
+            deserialize_bincode(&bincode_encoded).unwrap(),
+            deserialize_shortvec(&encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shortvec_rejects_length_beyond_u16_max() {
+        assert!(write_shortvec_len(u16::MAX as usize + 1, &mut Vec::new()).is_err());
+
+        let mut within_range = Vec::new();
+        write_shortvec_len(u16::MAX as usize, &mut within_range).unwrap();
+        assert_eq!(within_range, vec![0xFF, 0xFF, 0x03]);
+    }
+
+    #[test]
+    fn test_shortvec_rejects_non_canonical_trailing_zero_byte() {
+        // `0` canonically encodes as a single `0x00` byte; `0x80 0x00` encodes the same value
+        // with a needless continuation byte, which `read_shortvec_len` must reject.
+        assert!(deserialize_shortvec(&[0x80, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_shortvec_rejects_more_than_three_bytes() {
+        assert!(deserialize_shortvec(&[0x80, 0x80, 0x80, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_shortu16_round_trips_one_two_and_three_byte_widths() {
+        let mut enc = Encoder::new();
+        enc.write_shortu16(0);
+        enc.write_shortu16(127);
+        enc.write_shortu16(128);
+        enc.write_shortu16(16383);
+        enc.write_shortu16(16384);
+        enc.write_shortu16(u16::MAX);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_shortu16().unwrap(), 0);
+        assert_eq!(dec.read_shortu16().unwrap(), 127);
+        assert_eq!(dec.read_shortu16().unwrap(), 128);
+        assert_eq!(dec.read_shortu16().unwrap(), 16383);
+        assert_eq!(dec.read_shortu16().unwrap(), 16384);
+        assert_eq!(dec.read_shortu16().unwrap(), u16::MAX);
+    }
+
+    #[test]
+    fn test_shortu16_matches_shortvec_length_encoding() {
+        let mut enc = Encoder::new();
+        enc.write_shortu16(300);
+        assert_eq!(enc.finish(), vec![0xAC, 0x02]);
+    }
+
+    #[test]
+    fn test_shortu16_rejects_non_canonical_trailing_zero_byte() {
+        let mut dec = Decoder::new(&[0x80, 0x00]);
+        assert!(dec.read_shortu16().is_err());
+    }
+
+    #[test]
+    fn test_shortu16_rejects_more_than_three_bytes() {
+        let mut dec = Decoder::new(&[0x80, 0x80, 0x80, 0x01]);
+        assert!(dec.read_shortu16().is_err());
+    }
+
+    #[test]
+    fn test_serialize_into_deserialize_from_round_trip() {
+        let data = vec![1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+        let mut buf = Vec::new();
+        serialize_into(&data, &mut buf).unwrap();
+        assert_eq!(buf, serialize_bincode(&data));
+
+        let mut cursor = std::io::Cursor::new(buf);
+        let decoded = deserialize_from(&mut cursor).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_serialize_into_large_buffer_spans_chunks() {
+        // Larger than STREAM_CHUNK_SIZE so the chunked write path is exercised
+        let data = vec![0xAB; STREAM_CHUNK_SIZE * 3 + 17];
+
+        let mut buf = Vec::new();
+        serialize_into(&data, &mut buf).unwrap();
+
+        let mut cursor = std::io::Cursor::new(buf);
+        let decoded = deserialize_from(&mut cursor).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_file_encoder_small_writes_match_encoder() {
+        let mut out = Vec::new();
+        {
+            let mut fenc = FileEncoder::new(&mut out);
+            fenc.write_u8(42).unwrap();
+            fenc.write_u16(1234).unwrap();
+            fenc.write_u32(567890).unwrap();
+            fenc.write_u64(9876543210).unwrap();
+            fenc.write_varint(300).unwrap();
+            fenc.write_svarint(-300).unwrap();
+            let total = fenc.finish().unwrap();
+            assert_eq!(total, 1 + 2 + 4 + 8 + 2 + 2);
+        }
+
+        let mut dec = Decoder::new(&out);
+        assert_eq!(dec.read_u8().unwrap(), 42);
+        assert_eq!(dec.read_u16().unwrap(), 1234);
+        assert_eq!(dec.read_u32().unwrap(), 567890);
+        assert_eq!(dec.read_u64().unwrap(), 9876543210);
+        assert_eq!(dec.read_varint_fast().unwrap(), 300);
+        assert_eq!(dec.read_svarint().unwrap(), -300);
+    }
+
+    #[test]
+    fn test_file_encoder_flushes_when_buffer_fills() {
+        let mut out = Vec::new();
+        {
+            let mut fenc = FileEncoder::new(&mut out);
+            // One more u64 than fits in the 8 KiB staging buffer forces at least one flush.
+            let count = FILE_ENCODER_BUFFER_SIZE / 8 + 1;
+            for i in 0..count {
+                fenc.write_u64(i as u64).unwrap();
+            }
+            let total = fenc.finish().unwrap();
+            assert_eq!(total, (count * 8) as u64);
+        }
+
+        let mut dec = Decoder::new(&out);
+        let count = FILE_ENCODER_BUFFER_SIZE / 8 + 1;
+        for i in 0..count {
+            assert_eq!(dec.read_u64().unwrap(), i as u64);
+        }
+    }
+
+    #[test]
+    fn test_file_encoder_write_bytes_handles_blocks_larger_than_buffer() {
+        let data: Vec<u8> = (0..(FILE_ENCODER_BUFFER_SIZE * 3 + 17))
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        let mut out = Vec::new();
+        {
+            let mut fenc = FileEncoder::new(&mut out);
+            fenc.write_u32(7).unwrap();
+            fenc.write_bytes(&data).unwrap();
+            let total = fenc.finish().unwrap();
+            assert_eq!(total, 4 + data.len() as u64);
+        }
+
+        let mut dec = Decoder::new(&out);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        let mut read_back = vec![0u8; data.len()];
+        dec.read_bytes(&mut read_back).unwrap();
+        assert_eq!(read_back, data);
+    }
+
+    #[test]
+    fn test_file_decoder_reads_values_written_by_encoder() {
+        let mut enc = Encoder::new();
+        enc.write_u8(42);
+        enc.write_u16(1234);
+        enc.write_u32(567890);
+        enc.write_u64(9876543210);
+        enc.write_varint(300);
+        let bytes = enc.finish();
+
+        let mut fdec = FileDecoder::new(bytes.as_slice());
+        assert_eq!(fdec.read_u8().unwrap(), 42);
+        assert_eq!(fdec.read_u16().unwrap(), 1234);
+        assert_eq!(fdec.read_u32().unwrap(), 567890);
+        assert_eq!(fdec.read_u64().unwrap(), 9876543210);
+        assert_eq!(fdec.read_varint().unwrap(), 300);
+        assert_eq!(fdec.total_read(), bytes.len() as u64);
+    }
+
+    #[test]
+    fn test_file_decoder_reads_in_small_increments_from_a_slow_reader() {
+        // A reader that only ever returns 1 byte per `read` call exercises the loop in
+        // `read_bytes` that keeps pulling until the destination is full.
+        struct OneByteAtATime<'a>(&'a [u8]);
+        impl<'a> Read for OneByteAtATime<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() || buf.is_empty() {
+                    return Ok(0);
+                }
+                buf[0] = self.0[0];
+                self.0 = &self.0[1..];
+                Ok(1)
+            }
+        }
+
+        let mut enc = Encoder::new();
+        enc.write_u32(123456789);
+        let bytes = enc.finish();
+
+        let mut fdec = FileDecoder::new(OneByteAtATime(&bytes));
+        assert_eq!(fdec.read_u32().unwrap(), 123456789);
+    }
+
+    #[test]
+    fn test_file_decoder_reports_unexpected_end_on_truncated_stream() {
+        let mut fdec = FileDecoder::new(&[1u8, 2, 3][..]);
+        assert_eq!(
+            fdec.read_u64(),
+            Err(DecodeError::UnexpectedEnd {
+                needed: 8,
+                remaining: 3,
+                offset: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_file_decoder_varint_round_trips() {
+        let mut enc = Encoder::new();
+        enc.write_varint(127);
+        enc.write_varint(16383);
+        enc.write_varint(u64::MAX);
+        let bytes = enc.finish();
+
+        let mut fdec = FileDecoder::new(bytes.as_slice());
+        assert_eq!(fdec.read_varint().unwrap(), 127);
+        assert_eq!(fdec.read_varint().unwrap(), 16383);
+        assert_eq!(fdec.read_varint().unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn test_file_encoder_write_trait_interleaves_with_structured_writes() {
+        let mut out = Vec::new();
+        {
+            let mut fenc = FileEncoder::new(&mut out);
+            fenc.write_u32(7).unwrap();
+            std::io::Write::write_all(&mut fenc, b"raw payload").unwrap();
+            fenc.write_u8(9).unwrap();
+            fenc.finish().unwrap();
+        }
+
+        let mut fdec = FileDecoder::new(out.as_slice());
+        assert_eq!(fdec.read_u32().unwrap(), 7);
+        let mut raw = [0u8; 11];
+        fdec.read_bytes(&mut raw).unwrap();
+        assert_eq!(&raw, b"raw payload");
+        assert_eq!(fdec.read_u8().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_file_decoder_read_trait_passes_through_and_tracks_total_read() {
+        let mut fdec = FileDecoder::new(&[1u8, 2, 3, 4, 5][..]);
+        let mut buf = [0u8; 3];
+        let n = std::io::Read::read(&mut fdec, &mut buf).unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(buf, [1, 2, 3]);
+        assert_eq!(fdec.total_read(), 3);
+
+        let mut rest = Vec::new();
+        std::io::Read::read_to_end(&mut fdec, &mut rest).unwrap();
+        assert_eq!(rest, vec![4, 5]);
+        assert_eq!(fdec.total_read(), 5);
+    }
+
+    #[test]
+    fn test_file_decoder_shortu16_matches_decoder_shortu16() {
+        let mut enc = Encoder::new();
+        enc.write_shortu16(1);
+        enc.write_shortu16(300);
+        enc.write_shortu16(u16::MAX);
+        let bytes = enc.finish();
+
+        let mut fdec = FileDecoder::new(bytes.as_slice());
+        assert_eq!(fdec.read_shortu16().unwrap(), 1);
+        assert_eq!(fdec.read_shortu16().unwrap(), 300);
+        assert_eq!(fdec.read_shortu16().unwrap(), u16::MAX);
+    }
+
+    #[test]
+    fn test_file_decoder_shortu16_rejects_non_canonical_trailing_zero_byte() {
+        let mut fdec = FileDecoder::new(&[0x80, 0x00][..]);
+        assert_eq!(
+            fdec.read_shortu16().unwrap_err(),
+            DecodeError::InvalidVarint { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn test_file_decoder_shortu16_rejects_more_than_three_bytes() {
+        let mut fdec = FileDecoder::new(&[0x80, 0x80, 0x80, 0x01][..]);
+        assert_eq!(
+            fdec.read_shortu16().unwrap_err(),
+            DecodeError::InvalidVarint { offset: 0 }
+        );
+    }
+
+    /// Generic function over `Source`, exercised against both `Decoder` and `FileDecoder` below
+    fn sum_three_shortu16s<S: Source>(source: &mut S) -> u32 {
+        source.read_shortu16().unwrap() as u32
+            + source.read_shortu16().unwrap() as u32
+            + source.read_shortu16().unwrap() as u32
+    }
+
+    #[test]
+    fn test_source_trait_works_generically_over_decoder_and_file_decoder() {
+        let mut enc = Encoder::new();
+        enc.write_shortu16(1);
+        enc.write_shortu16(2);
+        enc.write_shortu16(3);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(sum_three_shortu16s(&mut dec), 6);
+
+        let mut fdec = FileDecoder::new(bytes.as_slice());
+        assert_eq!(sum_three_shortu16s(&mut fdec), 6);
+    }
+
+    #[test]
+    fn test_write_str_read_str_round_trips() {
+        let mut enc = Encoder::new();
+        enc.write_u32(7);
+        enc.write_str("hello limcode");
+        enc.write_u8(9);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        assert_eq!(dec.read_str().unwrap(), "hello limcode");
+        assert_eq!(dec.read_u8().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_read_str_validated_matches_read_str() {
+        let mut enc = Encoder::new();
+        enc.write_str("");
+        enc.write_str("a longer string with spaces and punctuation!");
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_str_validated().unwrap(), "");
+        assert_eq!(
+            dec.read_str_validated().unwrap(),
+            "a longer string with spaces and punctuation!"
+        );
+    }
+
+    #[test]
+    fn test_read_str_detects_desynchronized_stream() {
+        // Corrupt the length prefix so the sentinel check lands on a non-sentinel byte.
+        let mut enc = Encoder::new();
+        enc.write_str("hello");
+        let mut bytes = enc.finish();
+        bytes[0] = 4; // original length (5) truncated to 4, so the check lands on 'o', not the sentinel
+
+        let mut dec = Decoder::new(&bytes);
+        assert!(dec.read_str().is_err());
+    }
+
+    #[test]
+    fn test_to_hex_from_hex_round_trips() {
+        let data = b"\x00\x01\xabHello, limcode!\xff";
+        let hex = to_hex(data);
+        assert_eq!(hex, "0001ab48656c6c6f2c206c696d636f646521ff");
+        assert_eq!(from_hex(&hex).unwrap(), data);
+        assert_eq!(from_hex(&hex.to_uppercase()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_to_hex_spans_simd_lanes_and_scalar_remainder() {
+        // `to_hex` delegates to `serializer::hex_encode_into`, which dispatches at runtime to an
+        // AVX2 (32-byte), SSSE3 (16-byte), or scalar kernel depending on the host CPU - 37 bytes
+        // exercises a full SIMD lane plus a non-multiple scalar remainder on every tier.
+        let data: Vec<u8> = (0..37u16).map(|i| (i % 256) as u8).collect();
+        let hex = to_hex(&data);
+        assert_eq!(hex.len(), data.len() * 2);
+        assert_eq!(from_hex(&hex).unwrap(), data);
+    }
+
+    #[test]
+    fn test_write_hex_read_hex_round_trips() {
+        let mut enc = Encoder::new();
+        enc.write_u32(7);
+        enc.write_hex(b"\x00\x01\xabHello!\xff");
+        enc.write_u8(9);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        let mut out = vec![0u8; 10];
+        dec.read_hex(&mut out).unwrap();
+        assert_eq!(out, b"\x00\x01\xabHello!\xff");
+        assert_eq!(dec.read_u8().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_from_hex_rejects_odd_length_and_non_hex_bytes() {
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+    }
+
+    #[test]
+    fn test_write_f32_read_f32_round_trips() {
+        let mut enc = Encoder::new();
+        enc.write_f32(3.5);
+        enc.write_f32(f32::NAN);
+        enc.write_f32(f32::INFINITY);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_f32().unwrap(), 3.5);
+        assert!(dec.read_f32().unwrap().is_nan());
+        assert_eq!(dec.read_f32().unwrap(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_write_f32_compact_round_trips_every_case() {
+        let values: [f32; 7] = [0.0, 1.0, -42.0, 16_777_216.0, 0.1, f32::NAN, f32::INFINITY];
+
+        let mut enc = Encoder::new();
+        for &v in &values {
+            enc.write_f32_compact(v);
+        }
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        for &v in &values {
+            let decoded = dec.read_f32_compact().unwrap();
+            if v.is_nan() {
+                assert!(decoded.is_nan());
+            } else {
+                assert_eq!(decoded.to_bits(), v.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_f32_compact_is_smaller_for_small_integers() {
+        let mut compact = Encoder::new();
+        compact.write_f32_compact(1.0);
+        let compact_bytes = compact.finish();
+
+        let mut raw = Encoder::new();
+        raw.write_f32(1.0);
+        let raw_bytes = raw.finish();
+
+        assert!(compact_bytes.len() < raw_bytes.len());
+    }
+
+    #[test]
+    fn test_write_f64_read_f64_round_trips() {
+        let mut enc = Encoder::new();
+        enc.write_f64(2.71828);
+        enc.write_f64(f64::NEG_INFINITY);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_f64().unwrap(), 2.71828);
+        assert_eq!(dec.read_f64().unwrap(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_write_f64_compact_round_trips_every_case() {
+        let values: [f64; 6] = [0.0, -0.0, 7.0, 9_007_199_254_740_992.0, 1.5, f64::NAN];
+
+        let mut enc = Encoder::new();
+        for &v in &values {
+            enc.write_f64_compact(v);
+        }
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        for &v in &values {
+            let decoded = dec.read_f64_compact().unwrap();
+            if v.is_nan() {
+                assert!(decoded.is_nan());
+            } else {
+                assert_eq!(decoded.to_bits(), v.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_f32_compact_rejects_invalid_tag() {
+        let mut enc = Encoder::new();
+        enc.write_u8(0xAB);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert!(dec.read_f32_compact().is_err());
+    }
+
+    #[test]
+    fn test_default_endian_is_little() {
+        let mut enc = Encoder::new();
+        enc.write_u16(0x0102);
+        let bytes = enc.finish();
+        assert_eq!(bytes, vec![0x02, 0x01]);
+
+        let mut dec = Decoder::new(&bytes);
+        assert_eq!(dec.read_u16().unwrap(), 0x0102);
+    }
+
+    #[test]
+    fn test_big_endian_round_trips_and_flips_wire_bytes() {
+        let mut enc = Encoder::new().with_endian(Endian::Big);
+        enc.write_u16(0x0102);
+        enc.write_u32(0x0A0B0C0D);
+        enc.write_u64(0x0102030405060708);
+        let bytes = enc.finish();
+
+        // A little-endian decoder over the same bytes must see the byte-swapped values.
+        let mut le_view = Decoder::new(&bytes);
+        assert_eq!(le_view.read_u16().unwrap(), 0x0201);
+
+        let mut dec = Decoder::new(&bytes).with_endian(Endian::Big);
+        assert_eq!(dec.read_u16().unwrap(), 0x0102);
+        assert_eq!(dec.read_u32().unwrap(), 0x0A0B0C0D);
+        assert_eq!(dec.read_u64().unwrap(), 0x0102030405060708);
+    }
+
+    #[test]
+    fn test_read_vec_bincode_length_prefix_honors_endian() {
+        let mut enc = Encoder::new().with_endian(Endian::Big);
+        enc.write_u64(3);
+        enc.write_bytes(&[9, 8, 7]);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes).with_endian(Endian::Big);
+        assert_eq!(dec.read_vec_bincode().unwrap(), vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn test_decode_vec_iter_yields_elements_without_collecting() {
+        let mut enc = Encoder::new();
+        enc.write_u64(4);
+        for value in [10u32, 20, 30, 40] {
+            enc.write_u32(value);
+        }
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        let iter = dec.decode_vec_iter::<u32>().unwrap();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.collect::<Vec<u32>>(), vec![10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn test_decode_vec_iter_empty_array() {
+        let mut enc = Encoder::new();
+        enc.write_u64(0);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        let mut iter = dec.decode_vec_iter::<u64>().unwrap();
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_decode_vec_iter_rejects_claimed_length_past_remaining_buffer() {
+        let mut enc = Encoder::new();
+        enc.write_u64(10); // claims 10 u32s but no element bytes follow
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        assert!(dec.decode_vec_iter::<u32>().is_err());
+    }
+
+    #[test]
+    fn test_decode_vec_iter_honors_with_limit() {
+        let mut enc = Encoder::new();
+        enc.write_u64(100);
+        enc.write_bytes(&[0u8; 400]);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes).with_limit(16);
+        assert!(matches!(
+            dec.decode_vec_iter::<u32>(),
+            Err(DecodeError::LimitExceeded {
+                requested: 400,
+                limit: 16,
+                offset: 8,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_with_limit_rejects_oversized_length_prefix_before_allocating() {
+        // The length prefix claims more bytes than the configured limit allows, even though
+        // the underlying buffer actually has that many bytes available - the budget check must
+        // reject this itself rather than relying on running out of input.
+        let mut enc = Encoder::new();
+        enc.write_u64(1000);
+        enc.write_bytes(&[0u8; 1000]);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes).with_limit(16);
+        assert_eq!(
+            dec.read_vec_bincode(),
+            Err(DecodeError::LimitExceeded {
+                requested: 1000,
+                limit: 16,
+                offset: 8,
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_limit_accepts_reads_within_budget_and_decrements() {
+        let mut enc = Encoder::new();
+        enc.write_bytes(&[1, 2, 3, 4]);
+        enc.write_bytes(&[5, 6]);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes).with_limit(6);
+        let mut first = [0u8; 4];
+        dec.read_bytes(&mut first).unwrap();
+        assert_eq!(first, [1, 2, 3, 4]);
+
+        let mut second = [0u8; 2];
+        dec.read_bytes(&mut second).unwrap();
+        assert_eq!(second, [5, 6]);
+
+        // Budget is now fully spent; even a zero-byte-remaining-but-in-bounds read past it fails.
+        assert_eq!(
+            dec.read_bytes(&mut [0u8; 1]),
+            Err(DecodeError::UnexpectedEnd {
+                needed: 1,
+                remaining: 0,
+                offset: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn test_without_limit_still_bounds_reads_to_remaining_buffer() {
+        let mut enc = Encoder::new();
+        enc.write_bytes(&[1, 2]);
+        let bytes = enc.finish();
+
+        let mut dec = Decoder::new(&bytes);
+        let mut out = [0u8; 3];
+        assert_eq!(
+            dec.read_bytes(&mut out),
+            Err(DecodeError::UnexpectedEnd {
+                needed: 3,
+                remaining: 2,
+                offset: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_error_display_includes_offset() {
+        let err = DecodeError::UnexpectedEnd {
+            needed: 4,
+            remaining: 1,
+            offset: 12,
+        };
+        assert!(err.to_string().contains("offset 12"));
+
+        let as_str: &'static str = DecodeError::InvalidVarint { offset: 3 }.into();
+        assert_eq!(as_str, "invalid varint encoding");
+    }
 }
@@ -0,0 +1,413 @@
+//! RLP (Recursive Length Prefix) encoding, Ethereum's byte-string/list wire format
+//!
+//! Where [`crate::serialize_bincode`] wraps a single byte string in a fixed 8-byte length
+//! prefix, RLP's prefix is variable-width and self-describing: the leading byte alone tells a
+//! decoder whether what follows is a single byte, a short string, a long string, or a list, so
+//! (unlike the bincode path) no out-of-band type information is needed to walk a buffer. This
+//! makes the crate usable as a Rust-side encoder/decoder for data bound for EVM tooling, which
+//! expects exactly this format.
+//!
+//! [`RlpValue`] is the recursive `Bytes`/`List` shape RLP encodes - analogous to
+//! [`crate::value::Value`] for the tagged format, except RLP has no dedicated integer or string
+//! tag: integers are encoded as their minimal big-endian byte string (leading zeros stripped),
+//! and UTF-8 strings are encoded as their raw bytes.
+
+/// A single RLP item: either a byte string or an ordered list of items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RlpValue {
+    Bytes(Vec<u8>),
+    List(Vec<RlpValue>),
+}
+
+/// Error type for `serialize_rlp`/`deserialize_rlp`
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    /// The buffer ended before a declared length prefix or payload was fully read
+    Eof,
+    /// `deserialize_rlp` decoded one item but bytes remained after it
+    TrailingBytes,
+    /// A length prefix used the long form where the short form (or single-byte form) would
+    /// have sufficed - e.g. a string of length 1 encoded via `0x80 + len` instead of as itself,
+    /// or a long-form length with a leading zero byte
+    NonCanonicalLength,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Message(msg) => write!(f, "{}", msg),
+            Error::Eof => write!(f, "unexpected end of RLP input"),
+            Error::TrailingBytes => write!(f, "trailing bytes after a complete RLP item"),
+            Error::NonCanonicalLength => write!(f, "non-canonical RLP length encoding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Encode `value` as its RLP byte representation
+pub fn serialize_rlp(value: &RlpValue) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_into(value, &mut out);
+    out
+}
+
+/// Decode a single RLP item from `data`, erroring if any bytes remain afterward
+///
+/// Ethereum tooling treats a standalone RLP buffer as exactly one item (typically a list, for
+/// transactions/receipts/etc.) rather than a concatenated stream of items, so this requires the
+/// whole buffer to be consumed. Use [`decode_one`] directly if you need to walk a stream of
+/// back-to-back items.
+pub fn deserialize_rlp(data: &[u8]) -> Result<RlpValue, Error> {
+    let (value, consumed) = decode_one(data)?;
+    if consumed != data.len() {
+        return Err(Error::TrailingBytes);
+    }
+    Ok(value)
+}
+
+/// Decode a single RLP item from the start of `data`, returning it along with how many bytes it
+/// consumed - the bytes after that offset are left for the caller to decode separately
+pub fn decode_one(data: &[u8]) -> Result<(RlpValue, usize), Error> {
+    let first = *data.first().ok_or(Error::Eof)?;
+    match first {
+        0x00..=0x7F => Ok((RlpValue::Bytes(vec![first]), 1)),
+        0x80..=0xB7 => {
+            let len = (first - 0x80) as usize;
+            let payload = slice_or_eof(data, 1, len)?;
+            if len == 1 && payload[0] < 0x80 {
+                return Err(Error::NonCanonicalLength);
+            }
+            Ok((RlpValue::Bytes(payload.to_vec()), 1 + len))
+        }
+        0xB8..=0xBF => {
+            let len_of_len = (first - 0xB7) as usize;
+            let len = decode_length(slice_or_eof(data, 1, len_of_len)?)?;
+            if len <= 55 {
+                return Err(Error::NonCanonicalLength);
+            }
+            let payload = slice_or_eof(data, 1 + len_of_len, len)?;
+            Ok((RlpValue::Bytes(payload.to_vec()), 1 + len_of_len + len))
+        }
+        0xC0..=0xF7 => {
+            let len = (first - 0xC0) as usize;
+            let payload = slice_or_eof(data, 1, len)?;
+            Ok((RlpValue::List(decode_list_items(payload)?), 1 + len))
+        }
+        0xF8..=0xFF => {
+            let len_of_len = (first - 0xF7) as usize;
+            let len = decode_length(slice_or_eof(data, 1, len_of_len)?)?;
+            if len <= 55 {
+                return Err(Error::NonCanonicalLength);
+            }
+            let payload = slice_or_eof(data, 1 + len_of_len, len)?;
+            Ok((RlpValue::List(decode_list_items(payload)?), 1 + len_of_len + len))
+        }
+    }
+}
+
+fn encode_into(value: &RlpValue, out: &mut Vec<u8>) {
+    match value {
+        RlpValue::Bytes(bytes) => encode_bytes(bytes, out),
+        RlpValue::List(items) => {
+            let mut payload = Vec::new();
+            for item in items {
+                encode_into(item, &mut payload);
+            }
+            encode_length_prefix(payload.len(), 0xC0, 0xF7, out);
+            out.extend_from_slice(&payload);
+        }
+    }
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        out.push(bytes[0]);
+        return;
+    }
+    encode_length_prefix(bytes.len(), 0x80, 0xB7, out);
+    out.extend_from_slice(bytes);
+}
+
+/// Write a length prefix: `short_base + len` for `len <= 55`, otherwise `long_base +
+/// len_of_len` followed by `len`'s minimal big-endian encoding
+fn encode_length_prefix(len: usize, short_base: u8, long_base: u8, out: &mut Vec<u8>) {
+    if len <= 55 {
+        out.push(short_base + len as u8);
+    } else {
+        let len_bytes = minimal_be_bytes(len as u64);
+        out.push(long_base + len_bytes.len() as u8);
+        out.extend_from_slice(&len_bytes);
+    }
+}
+
+/// `n`'s big-endian representation with leading zero bytes stripped (RLP never pads lengths)
+fn minimal_be_bytes(n: u64) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+    bytes[first_nonzero..].to_vec()
+}
+
+fn decode_length(bytes: &[u8]) -> Result<usize, Error> {
+    if bytes.first() == Some(&0) {
+        return Err(Error::NonCanonicalLength);
+    }
+    if bytes.len() > std::mem::size_of::<usize>() {
+        return Err(Error::Message(
+            "RLP length prefix too large for this platform's usize".to_string(),
+        ));
+    }
+    let mut buf = [0u8; std::mem::size_of::<usize>()];
+    buf[std::mem::size_of::<usize>() - bytes.len()..].copy_from_slice(bytes);
+    Ok(usize::from_be_bytes(buf))
+}
+
+/// Encode `value` as a single length byte followed by its minimal big-endian byte string
+/// (leading zero bytes stripped), the way Ethereum RLP encodes integers when it isn't routing
+/// them through the general [`RlpValue::Bytes`]/short-string-elision rules
+///
+/// Unlike [`RlpValue::Bytes`]'s encoding (which special-cases a lone byte below `0x80` to need
+/// no prefix at all), this always writes an explicit length byte, so `0` costs exactly one byte
+/// (length `0`, no payload) and every other value costs `1 + minimal_byte_width(value)` bytes -
+/// simpler and slightly larger than full RLP, but self-delimiting without needing to distinguish
+/// the single-byte special case on decode.
+pub fn serialize_rlp_int(value: u64) -> Vec<u8> {
+    let bytes = minimal_be_int_bytes(value);
+    let mut out = Vec::with_capacity(1 + bytes.len());
+    out.push(bytes.len() as u8);
+    out.extend_from_slice(&bytes);
+    out
+}
+
+/// Inverse of `serialize_rlp_int`, returning the decoded value along with how many bytes of
+/// `data` it consumed so callers can decode a back-to-back run of them (see
+/// `deserialize_rlp_int_vec`)
+pub fn deserialize_rlp_int(data: &[u8]) -> Result<(u64, usize), Error> {
+    let len = *data.first().ok_or(Error::Eof)? as usize;
+    if len > 8 {
+        return Err(Error::Message(format!(
+            "rlp int length prefix {} exceeds u64's 8-byte width",
+            len
+        )));
+    }
+    let payload = slice_or_eof(data, 1, len)?;
+    if len > 0 && payload[0] == 0 {
+        return Err(Error::NonCanonicalLength);
+    }
+    let mut buf = [0u8; 8];
+    buf[8 - len..].copy_from_slice(payload);
+    Ok((u64::from_be_bytes(buf), 1 + len))
+}
+
+/// `serialize_rlp_int` applied to every element of `values`, concatenated back to back with no
+/// outer length or count prefix - the count is implicit in how many integers `deserialize_rlp_int_vec`
+/// manages to decode before running out of bytes
+pub fn serialize_rlp_int_vec(values: &[u64]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &value in values {
+        out.extend_from_slice(&serialize_rlp_int(value));
+    }
+    out
+}
+
+/// Inverse of `serialize_rlp_int_vec`: decodes back-to-back `serialize_rlp_int` items until
+/// `data` is exhausted, erroring (rather than panicking or silently truncating) if a length
+/// prefix ever claims more bytes than remain
+pub fn deserialize_rlp_int_vec(data: &[u8]) -> Result<Vec<u64>, Error> {
+    let mut out = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let (value, consumed) = deserialize_rlp_int(&data[offset..])?;
+        out.push(value);
+        offset += consumed;
+    }
+    Ok(out)
+}
+
+/// `value`'s big-endian representation with leading zero bytes stripped - unlike
+/// `minimal_be_bytes`, `0` strips away to an *empty* byte string rather than `vec![0]`, matching
+/// how RLP encodes the integer zero (as opposed to RLP's length-prefix encoding, which never
+/// needs to represent a length of zero this way)
+fn minimal_be_int_bytes(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    match bytes.iter().position(|&b| b != 0) {
+        Some(first_nonzero) => bytes[first_nonzero..].to_vec(),
+        None => Vec::new(),
+    }
+}
+
+fn decode_list_items(mut data: &[u8]) -> Result<Vec<RlpValue>, Error> {
+    let mut items = Vec::new();
+    while !data.is_empty() {
+        let (item, consumed) = decode_one(data)?;
+        items.push(item);
+        data = &data[consumed..];
+    }
+    Ok(items)
+}
+
+fn slice_or_eof(data: &[u8], start: usize, len: usize) -> Result<&[u8], Error> {
+    data.get(start..start + len).ok_or(Error::Eof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_byte_below_0x80_encodes_as_itself() {
+        assert_eq!(serialize_rlp(&RlpValue::Bytes(vec![0x00])), vec![0x00]);
+        assert_eq!(serialize_rlp(&RlpValue::Bytes(vec![0x7f])), vec![0x7f]);
+    }
+
+    #[test]
+    fn test_empty_string_encodes_as_0x80() {
+        assert_eq!(serialize_rlp(&RlpValue::Bytes(vec![])), vec![0x80]);
+    }
+
+    #[test]
+    fn test_short_string_gets_0x80_plus_len_prefix() {
+        let value = RlpValue::Bytes(b"dog".to_vec());
+        assert_eq!(serialize_rlp(&value), vec![0x83, b'd', b'o', b'g']);
+    }
+
+    #[test]
+    fn test_long_string_gets_0xb7_plus_len_of_len_prefix() {
+        let bytes: Vec<u8> = (0..56).map(|i| i as u8).collect();
+        let encoded = serialize_rlp(&RlpValue::Bytes(bytes.clone()));
+        assert_eq!(encoded[0], 0xB8);
+        assert_eq!(encoded[1], 56);
+        assert_eq!(&encoded[2..], &bytes[..]);
+    }
+
+    #[test]
+    fn test_empty_list_encodes_as_0xc0() {
+        assert_eq!(serialize_rlp(&RlpValue::List(vec![])), vec![0xC0]);
+    }
+
+    #[test]
+    fn test_list_of_short_strings_round_trips() {
+        let value = RlpValue::List(vec![
+            RlpValue::Bytes(b"cat".to_vec()),
+            RlpValue::Bytes(b"dog".to_vec()),
+        ]);
+        let encoded = serialize_rlp(&value);
+        assert_eq!(encoded, vec![0xC8, 0x83, b'c', b'a', b't', 0x83, b'd', b'o', b'g']);
+        assert_eq!(deserialize_rlp(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_nested_lists_round_trip() {
+        let value = RlpValue::List(vec![
+            RlpValue::List(vec![]),
+            RlpValue::List(vec![RlpValue::List(vec![])]),
+            RlpValue::List(vec![
+                RlpValue::List(vec![]),
+                RlpValue::List(vec![RlpValue::List(vec![])]),
+            ]),
+        ]);
+        let encoded = serialize_rlp(&value);
+        assert_eq!(deserialize_rlp(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_long_list_round_trips() {
+        let items: Vec<RlpValue> = (0..20)
+            .map(|i| RlpValue::Bytes(vec![i as u8; 5]))
+            .collect();
+        let value = RlpValue::List(items);
+        let encoded = serialize_rlp(&value);
+        assert_eq!(encoded[0], 0xF8);
+        assert_eq!(deserialize_rlp(&encoded).unwrap(), value);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_truncated_short_string() {
+        let err = deserialize_rlp(&[0x83, b'd', b'o']).unwrap_err();
+        assert!(matches!(err, Error::Eof));
+    }
+
+    #[test]
+    fn test_deserialize_rejects_trailing_bytes() {
+        let err = deserialize_rlp(&[0x80, 0x00]).unwrap_err();
+        assert!(matches!(err, Error::TrailingBytes));
+    }
+
+    #[test]
+    fn test_deserialize_rejects_non_canonical_single_byte_string() {
+        // 0x00 should have been encoded as itself, not as a length-1 string
+        let err = deserialize_rlp(&[0x81, 0x00]).unwrap_err();
+        assert!(matches!(err, Error::NonCanonicalLength));
+    }
+
+    #[test]
+    fn test_deserialize_rejects_long_form_length_that_fits_in_short_form() {
+        let bytes = vec![0u8; 10];
+        let mut encoded = vec![0xB8, 10];
+        encoded.extend_from_slice(&bytes);
+        let err = deserialize_rlp(&encoded).unwrap_err();
+        assert!(matches!(err, Error::NonCanonicalLength));
+    }
+
+    #[test]
+    fn test_minimal_be_bytes_strips_leading_zeros() {
+        assert_eq!(minimal_be_bytes(56), vec![56]);
+        assert_eq!(minimal_be_bytes(256), vec![1, 0]);
+        assert_eq!(minimal_be_bytes(0), vec![0]);
+    }
+
+    #[test]
+    fn test_serialize_rlp_int_zero_costs_exactly_one_byte() {
+        assert_eq!(serialize_rlp_int(0), vec![0]);
+    }
+
+    #[test]
+    fn test_serialize_rlp_int_round_trips_small_and_large_values() {
+        for value in [0u64, 1, 55, 56, 255, 256, 0xFFFF, u64::MAX] {
+            let encoded = serialize_rlp_int(value);
+            let (decoded, consumed) = deserialize_rlp_int(&encoded).unwrap();
+            assert_eq!(decoded, value);
+            assert_eq!(consumed, encoded.len());
+        }
+    }
+
+    #[test]
+    fn test_serialize_rlp_int_strips_leading_zero_bytes() {
+        assert_eq!(serialize_rlp_int(1), vec![1, 1]);
+        assert_eq!(serialize_rlp_int(256), vec![2, 1, 0]);
+        assert_eq!(serialize_rlp_int(u64::MAX), {
+            let mut expected = vec![8];
+            expected.extend_from_slice(&[0xFF; 8]);
+            expected
+        });
+    }
+
+    #[test]
+    fn test_deserialize_rlp_int_rejects_truncated_payload() {
+        let err = deserialize_rlp_int(&[2, 0x01]).unwrap_err();
+        assert!(matches!(err, Error::Eof));
+    }
+
+    #[test]
+    fn test_deserialize_rlp_int_rejects_non_canonical_leading_zero() {
+        let err = deserialize_rlp_int(&[1, 0x00]).unwrap_err();
+        assert!(matches!(err, Error::NonCanonicalLength));
+    }
+
+    #[test]
+    fn test_rlp_int_vec_round_trips_and_is_self_delimiting() {
+        let values: Vec<u64> = vec![0, 1, 55, 56, 1000, u64::MAX, 0];
+        let encoded = serialize_rlp_int_vec(&values);
+        let decoded = deserialize_rlp_int_vec(&encoded).unwrap();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_rlp_int_vec_smaller_than_fixed_width_for_small_values() {
+        let values: Vec<u64> = (0..64).collect();
+        let rlp = serialize_rlp_int_vec(&values);
+        let fixed = crate::serializer::serialize_pod(&values).unwrap();
+        assert!(rlp.len() < fixed.len());
+    }
+}
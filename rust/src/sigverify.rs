@@ -0,0 +1,211 @@
+//! Parallel batch ed25519 signature verification for packed transactions
+//!
+//! [`crate::txparse::parse_tx_offsets`] locates a transaction's signatures, signer pubkeys, and
+//! signed message without deserializing it; this module is what that was built for - a validator
+//! ingest path where signature verification dominates and has to run across every core, not one
+//! packet at a time. [`verify_batch`] farms a slice of packed transaction packets out to a rayon
+//! thread pool in fixed-size chunks (bounding tail latency the way one packet per task wouldn't)
+//! and returns a same-length `Vec<bool>` of per-packet results.
+//!
+//! A packet passes only if every one of its `num_required_signatures` signatures verifies against
+//! the corresponding signer pubkey (the message's account-keys array is ordered so the first
+//! `num_required_signatures` entries are exactly the signers, in signature order) over the same
+//! signed message bytes. Anything [`crate::txparse::parse_tx_offsets`] rejects, or any signature
+//! that fails ed25519 verification, fails the whole packet.
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use rayon::prelude::*;
+
+use crate::txparse::{self, PUBKEY_LEN, SIGNATURE_LEN};
+
+/// Number of packets handed to a single rayon work unit
+///
+/// Small enough to keep tail latency bounded (a straggler packet only delays one chunk's worth of
+/// work, not the whole batch), large enough that per-chunk scheduling overhead stays negligible.
+pub const VERIFY_CHUNK_SIZE: usize = 128;
+
+/// Verify every signature of one packed transaction packet
+///
+/// Returns `false` if the packet doesn't even parse as well-formed (see
+/// [`crate::txparse::parse_tx_offsets`]), if `num_required_signatures` is zero or larger than the
+/// number of signatures actually present or the number of account-key pubkeys actually parsed,
+/// or if any required signature fails to verify.
+fn verify_packet(packet: &[u8]) -> bool {
+    let Some(offsets) = txparse::parse_tx_offsets(packet) else {
+        return false;
+    };
+    let num_required_signatures = packet[offsets.msg_offset] as usize;
+    if num_required_signatures == 0
+        || num_required_signatures > offsets.sig_count
+        || num_required_signatures > offsets.pubkey_count
+    {
+        return false;
+    }
+    let message = &packet[offsets.msg_offset..offsets.msg_offset + offsets.msg_len];
+
+    for i in 0..num_required_signatures {
+        let sig_bytes: &[u8; SIGNATURE_LEN] = match packet
+            [offsets.sig_offset + i * SIGNATURE_LEN..offsets.sig_offset + (i + 1) * SIGNATURE_LEN]
+            .try_into()
+        {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+        let pubkey_bytes: &[u8; PUBKEY_LEN] = match packet
+            [offsets.pubkey_offset + i * PUBKEY_LEN..offsets.pubkey_offset + (i + 1) * PUBKEY_LEN]
+            .try_into()
+        {
+            Ok(bytes) => bytes,
+            Err(_) => return false,
+        };
+        let Ok(verifying_key) = VerifyingKey::from_bytes(pubkey_bytes) else {
+            return false;
+        };
+        let signature = Signature::from_bytes(sig_bytes);
+        if verifying_key.verify(message, &signature).is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+/// Verify a batch of packed transaction packets in parallel, using `num_threads` worker threads
+///
+/// See [`verify_batch`] for the pass/fail rule applied to each packet.
+pub fn verify_batch_with_threads(packets: &[&[u8]], num_threads: usize) -> Vec<bool> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads.max(1))
+        .build()
+        .expect("building a rayon thread pool with a positive thread count cannot fail");
+    pool.install(|| {
+        packets
+            .par_chunks(VERIFY_CHUNK_SIZE)
+            .flat_map(|chunk| chunk.iter().map(|packet| verify_packet(packet)).collect::<Vec<_>>())
+            .collect()
+    })
+}
+
+/// Verify a batch of packed transaction packets in parallel, on a pool sized to rayon's default
+/// thread count
+///
+/// Equivalent to [`verify_batch_with_threads`] with `num_threads` set to rayon's default (one
+/// worker per available core) - call [`verify_batch_with_threads`] directly when the caller needs
+/// to size the pool itself, e.g. to leave cores free for other ingest-path work.
+pub fn verify_batch(packets: &[&[u8]]) -> Vec<bool> {
+    verify_batch_with_threads(packets, rayon::current_num_threads())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn build_signed_packet(signers: &[SigningKey], extra_account_keys: usize) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.push(signers.len() as u8); // num_required_signatures
+        message.push(0); // num_readonly_signed
+        message.push(1); // num_readonly_unsigned
+        crate::write_shortvec_len(signers.len() + extra_account_keys, &mut message).unwrap();
+        for signer in signers {
+            message.extend_from_slice(signer.verifying_key().as_bytes());
+        }
+        for i in 0..extra_account_keys {
+            message.extend(std::iter::repeat(0xEEu8.wrapping_add(i as u8)).take(PUBKEY_LEN));
+        }
+        message.extend_from_slice(b"recentblockhash!"[..8].as_ref());
+
+        let mut packet = Vec::new();
+        crate::write_shortvec_len(signers.len(), &mut packet).unwrap();
+        for signer in signers {
+            let signature: Signature = signer.sign(&message);
+            packet.extend_from_slice(&signature.to_bytes());
+        }
+        packet.extend_from_slice(&message);
+        packet
+    }
+
+    #[test]
+    fn test_verify_batch_passes_well_formed_single_signer_packets() {
+        let signer = SigningKey::from_bytes(&[7u8; 32]);
+        let packet = build_signed_packet(std::slice::from_ref(&signer), 1);
+        let packets: Vec<&[u8]> = vec![&packet];
+        assert_eq!(verify_batch(&packets), vec![true]);
+    }
+
+    #[test]
+    fn test_verify_batch_passes_multi_signer_packets_only_when_every_signature_verifies() {
+        let signer_a = SigningKey::from_bytes(&[1u8; 32]);
+        let signer_b = SigningKey::from_bytes(&[2u8; 32]);
+        let good = build_signed_packet(&[signer_a.clone(), signer_b.clone()], 0);
+        assert_eq!(verify_batch(&[&good[..]]), vec![true]);
+
+        let mut tampered = good.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xFF;
+        assert_eq!(verify_batch(&[&tampered[..]]), vec![false]);
+    }
+
+    #[test]
+    fn test_verify_batch_fails_a_packet_with_a_flipped_signature_byte() {
+        let signer = SigningKey::from_bytes(&[3u8; 32]);
+        let mut packet = build_signed_packet(std::slice::from_ref(&signer), 0);
+        packet[1] ^= 0x01; // corrupt a byte inside the signature
+        assert_eq!(verify_batch(&[&packet[..]]), vec![false]);
+    }
+
+    #[test]
+    fn test_verify_batch_fails_an_unparseable_packet_without_panicking() {
+        let packets: Vec<&[u8]> = vec![&[0x80, 0x00]];
+        assert_eq!(verify_batch(&packets), vec![false]);
+    }
+
+    /// A packet that parses successfully (`sig_count` matches the declared signature count) but
+    /// whose message lies about `num_required_signatures`, claiming more required signers than
+    /// account-key pubkeys are actually present. This must be rejected rather than panicking when
+    /// `verify_packet` tries to slice out the `i`-th pubkey.
+    fn build_packet_lying_about_required_signatures(
+        num_required_signatures: usize,
+        real_account_key_count: usize,
+    ) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.push(num_required_signatures as u8);
+        message.push(0); // num_readonly_signed
+        message.push(1); // num_readonly_unsigned
+        crate::write_shortvec_len(real_account_key_count, &mut message).unwrap();
+        for i in 0..real_account_key_count {
+            message.extend(std::iter::repeat(0xEEu8.wrapping_add(i as u8)).take(PUBKEY_LEN));
+        }
+        message.extend_from_slice(&[0xFF; 8]); // stand-in for the rest of the message
+
+        let mut packet = Vec::new();
+        crate::write_shortvec_len(num_required_signatures, &mut packet).unwrap();
+        packet.extend(std::iter::repeat(0xAAu8).take(num_required_signatures * SIGNATURE_LEN));
+        packet.extend_from_slice(&message);
+        packet
+    }
+
+    #[test]
+    fn test_verify_batch_rejects_a_packet_claiming_more_required_signers_than_account_keys() {
+        let packet = build_packet_lying_about_required_signatures(5, 1);
+        assert_eq!(verify_batch(&[&packet[..]]), vec![false]);
+    }
+
+    #[test]
+    fn test_verify_batch_handles_a_mixed_batch_spanning_multiple_chunks() {
+        let signer = SigningKey::from_bytes(&[9u8; 32]);
+        let good = build_signed_packet(std::slice::from_ref(&signer), 0);
+        let mut bad = good.clone();
+        let last = bad.len() - 1;
+        bad[last] ^= 0xFF;
+
+        let mut packets: Vec<&[u8]> = Vec::new();
+        for i in 0..(VERIFY_CHUNK_SIZE + 10) {
+            packets.push(if i % 2 == 0 { &good[..] } else { &bad[..] });
+        }
+        let results = verify_batch_with_threads(&packets, 2);
+        assert_eq!(results.len(), packets.len());
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(*result, i % 2 == 0, "packet {i} mismatched");
+        }
+    }
+}
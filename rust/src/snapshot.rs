@@ -30,6 +30,16 @@ use std::fs::File;
 use std::io::{self, BufReader, Read};
 use std::path::Path;
 
+#[cfg(feature = "solana")]
+use base64::Engine;
+#[cfg(feature = "solana")]
+use bzip2::read::BzDecoder;
+#[cfg(feature = "solana")]
+use flate2::read::GzDecoder;
+#[cfg(feature = "solana")]
+use lz4_flex::frame::FrameDecoder as Lz4Decoder;
+#[cfg(feature = "solana")]
+use regex::Regex;
 #[cfg(feature = "solana")]
 use tar::Archive;
 #[cfg(feature = "solana")]
@@ -55,9 +65,11 @@ pub struct SnapshotAccount {
 pub struct SnapshotManifest {
     pub slot: u64,
     pub bank_hash: [u8; 32],
+    pub parent_hash: [u8; 32],
     pub parent_slot: u64,
     pub epoch: u64,
     pub block_height: u64,
+    pub capitalization: u64,
     /// Raw manifest data for advanced parsing
     pub raw_data: Vec<u8>,
 }
@@ -82,6 +94,15 @@ pub enum SnapshotItem {
     StatusCache(Vec<u8>),
     /// Individual account from AppendVec
     Account(SnapshotAccount),
+    /// Bank manifest from an *incremental* snapshot archive, tagged with the base slot the
+    /// incremental snapshot was taken against (parsed from the archive's
+    /// `incremental-snapshot-<base_slot>-<slot>-<hash>.tar.zst` filename). Callers should check
+    /// `base_slot` against the full snapshot's `Manifest(..).slot` before calling
+    /// [`merge_snapshots`].
+    IncrementalManifest {
+        base_slot: u64,
+        manifest: SnapshotManifest,
+    },
     /// Unknown/other file in archive
     OtherFile { path: String, data: Vec<u8> },
 }
@@ -99,6 +120,203 @@ pub struct SnapshotStats {
     pub max_account_size: usize,
 }
 
+/// Compression format of a Solana snapshot archive, inferred from its filename suffix
+///
+/// Solana snapshot archives are a tar stream wrapped in one of several compressors; the suffix
+/// is the only signal available for which one, so every entry point in this module goes through
+/// [`ArchiveFormat::from_path`] rather than hardcoding a single decoder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zstd,
+    Gzip,
+    Bzip2,
+    Lz4,
+    /// Uncompressed tar, no wrapping decoder needed
+    Tar,
+}
+
+impl ArchiveFormat {
+    /// Infer the archive format from a path's filename suffix (`.tar.zst`, `.tar.gz`,
+    /// `.tar.bz2`, `.tar.lz4`, or plain `.tar`)
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file_name = path.as_ref().file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "snapshot path has no file name")
+        })?;
+
+        if file_name.ends_with(".tar.zst") {
+            Ok(ArchiveFormat::Zstd)
+        } else if file_name.ends_with(".tar.gz") {
+            Ok(ArchiveFormat::Gzip)
+        } else if file_name.ends_with(".tar.bz2") {
+            Ok(ArchiveFormat::Bzip2)
+        } else if file_name.ends_with(".tar.lz4") {
+            Ok(ArchiveFormat::Lz4)
+        } else if file_name.ends_with(".tar") {
+            Ok(ArchiveFormat::Tar)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unrecognized snapshot archive format: {file_name}"),
+            ))
+        }
+    }
+}
+
+/// Open `path` and wrap it in the decoder matching its [`ArchiveFormat`], ready for
+/// `tar::Archive::new`
+///
+/// All of `stream_snapshot`, `stream_snapshot_full`, and `extract_snapshot` route through here
+/// so adding a new archive format only requires a new `ArchiveFormat` variant and a new match arm.
+#[cfg(feature = "solana")]
+fn open_archive_decoder<P: AsRef<Path>>(path: P, buf_capacity: usize) -> io::Result<Box<dyn Read>> {
+    let format = ArchiveFormat::from_path(path.as_ref())?;
+    let file = File::open(path)?;
+    let buf_reader = BufReader::with_capacity(buf_capacity, file);
+
+    let decoder: Box<dyn Read> = match format {
+        ArchiveFormat::Zstd => Box::new(ZstdDecoder::new(buf_reader)?),
+        ArchiveFormat::Gzip => Box::new(GzDecoder::new(buf_reader)),
+        ArchiveFormat::Bzip2 => Box::new(BzDecoder::new(buf_reader)),
+        ArchiveFormat::Lz4 => Box::new(Lz4Decoder::new(buf_reader)),
+        ArchiveFormat::Tar => Box::new(buf_reader),
+    };
+
+    Ok(decoder)
+}
+
+/// Metadata parsed from a snapshot archive's filename by [`parse_archive_filename`]
+///
+/// Mirrors the runtime's `SnapshotArchiveInfoGetter` distinction between full and incremental
+/// archives, which `bank_from_latest_snapshot_archives` uses to pick the newest full snapshot
+/// and the newest compatible incremental snapshot layered on top of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotArchiveInfo {
+    Full {
+        slot: u64,
+        hash: [u8; 32],
+        format: ArchiveFormat,
+    },
+    Incremental {
+        base_slot: u64,
+        slot: u64,
+        hash: [u8; 32],
+        format: ArchiveFormat,
+    },
+}
+
+impl SnapshotArchiveInfo {
+    /// The archive's own slot - the end slot for both full and incremental archives
+    pub fn slot(&self) -> u64 {
+        match self {
+            SnapshotArchiveInfo::Full { slot, .. } => *slot,
+            SnapshotArchiveInfo::Incremental { slot, .. } => *slot,
+        }
+    }
+
+    /// The bank hash embedded in the filename
+    pub fn hash(&self) -> [u8; 32] {
+        match self {
+            SnapshotArchiveInfo::Full { hash, .. } => *hash,
+            SnapshotArchiveInfo::Incremental { hash, .. } => *hash,
+        }
+    }
+
+    /// The archive's compression format
+    pub fn format(&self) -> ArchiveFormat {
+        match self {
+            SnapshotArchiveInfo::Full { format, .. } => *format,
+            SnapshotArchiveInfo::Incremental { format, .. } => *format,
+        }
+    }
+}
+
+#[cfg(feature = "solana")]
+fn full_snapshot_regex() -> &'static Regex {
+    static RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^snapshot-(\d+)-([1-9A-HJ-NP-Za-km-z]+)\.tar(?:\.(?:zst|gz|bz2|lz4))?$")
+            .unwrap()
+    })
+}
+
+#[cfg(feature = "solana")]
+fn incremental_snapshot_regex() -> &'static Regex {
+    static RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^incremental-snapshot-(\d+)-(\d+)-([1-9A-HJ-NP-Za-km-z]+)\.tar(?:\.(?:zst|gz|bz2|lz4))?$",
+        )
+        .unwrap()
+    })
+}
+
+#[cfg(feature = "solana")]
+fn invalid_archive_filename(file_name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("not a recognized snapshot archive filename: {file_name}"),
+    )
+}
+
+#[cfg(feature = "solana")]
+fn decode_archive_hash(encoded: &str, file_name: &str) -> io::Result<[u8; 32]> {
+    let decoded = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|_| invalid_archive_filename(file_name))?;
+    decoded.try_into().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("snapshot hash in {file_name} does not decode to 32 bytes"),
+        )
+    })
+}
+
+/// Parse a snapshot archive's filename into its [`SnapshotArchiveInfo`]
+///
+/// Recognizes both the full (`snapshot-<slot>-<hash>.tar.zst`) and incremental
+/// (`incremental-snapshot-<base_slot>-<slot>-<hash>.tar.zst`) naming conventions, across every
+/// [`ArchiveFormat`] this module supports. `hash` is base58-decoded into a `[u8; 32]` the same
+/// way the runtime's `Hash` type round-trips through its `Display`/`FromStr` impls.
+///
+/// Callers selecting the newest snapshot in a directory (the runtime's
+/// `bank_from_latest_snapshot_archives` selection logic) can compare every entry's
+/// [`SnapshotArchiveInfo::slot`], and should check the resulting `slot`/`base_slot` against the
+/// `SnapshotManifest.slot` parsed from the archive's contents before trusting the filename alone.
+#[cfg(feature = "solana")]
+pub fn parse_archive_filename<P: AsRef<Path>>(path: P) -> io::Result<SnapshotArchiveInfo> {
+    let path = path.as_ref();
+    let file_name = path.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "snapshot path has no file name")
+    })?;
+    let format = ArchiveFormat::from_path(path)?;
+
+    if let Some(caps) = incremental_snapshot_regex().captures(file_name) {
+        let base_slot = caps[1]
+            .parse()
+            .map_err(|_| invalid_archive_filename(file_name))?;
+        let slot = caps[2]
+            .parse()
+            .map_err(|_| invalid_archive_filename(file_name))?;
+        let hash = decode_archive_hash(&caps[3], file_name)?;
+        return Ok(SnapshotArchiveInfo::Incremental {
+            base_slot,
+            slot,
+            hash,
+            format,
+        });
+    }
+
+    if let Some(caps) = full_snapshot_regex().captures(file_name) {
+        let slot = caps[1]
+            .parse()
+            .map_err(|_| invalid_archive_filename(file_name))?;
+        let hash = decode_archive_hash(&caps[2], file_name)?;
+        return Ok(SnapshotArchiveInfo::Full { slot, hash, format });
+    }
+
+    Err(invalid_archive_filename(file_name))
+}
+
 /// AppendVec account header (136 bytes) - kept for documentation
 #[allow(dead_code)]
 #[repr(C, packed)]
@@ -115,6 +333,50 @@ struct AccountHeader {
                         // Total: 136 bytes (0x88)
 }
 
+/// Size in bytes of the fixed-size AppendVec record header `decode_appendvec_header` reads
+#[cfg(feature = "solana")]
+const APPENDVEC_HEADER_SIZE: usize = 136;
+
+/// Fixed-size fields of a single AppendVec account record header, borrowed from the backing
+/// buffer rather than copied
+///
+/// Every owned/borrowed/filtered iteration path (`parse_appendvec`, `SnapshotAccountIterator`,
+/// `parse_appendvec_record_ref`, `FilteredSnapshotIterator`, `FullSnapshotIterator`,
+/// `parse_appendvec_record`) decodes this same layout at the same fixed offsets; factoring it out
+/// here means the field-offset layout - and any future fix to how it's read - only has to live
+/// (and be checked) in one place.
+#[cfg(feature = "solana")]
+struct AppendVecHeader<'a> {
+    write_version: u64,
+    data_len: usize,
+    pubkey: &'a [u8],
+    lamports: u64,
+    rent_epoch: u64,
+    owner: &'a [u8],
+    executable: bool,
+    hash: &'a [u8],
+}
+
+/// Decode the `APPENDVEC_HEADER_SIZE`-byte record header at `offset`
+///
+/// Callers must ensure `offset + APPENDVEC_HEADER_SIZE <= data.len()` before calling; this
+/// mirrors `parse_appendvec_record_ref`'s existing contract and isn't re-checked here so callers
+/// that have already validated it (e.g. from `appendvec_record_offsets`) don't pay for it twice.
+#[cfg(feature = "solana")]
+fn decode_appendvec_header(data: &[u8], offset: usize) -> AppendVecHeader<'_> {
+    AppendVecHeader {
+        write_version: u64::from_le_bytes(data[offset..offset + 0x08].try_into().unwrap()),
+        data_len: u64::from_le_bytes(data[offset + 0x08..offset + 0x10].try_into().unwrap())
+            as usize,
+        pubkey: &data[offset + 0x10..offset + 0x30],
+        lamports: u64::from_le_bytes(data[offset + 0x30..offset + 0x38].try_into().unwrap()),
+        rent_epoch: u64::from_le_bytes(data[offset + 0x38..offset + 0x40].try_into().unwrap()),
+        owner: &data[offset + 0x40..offset + 0x60],
+        executable: data[offset + 0x60] != 0,
+        hash: &data[offset + 0x68..offset + 0x88],
+    }
+}
+
 /// Parse accounts from AppendVec file data
 ///
 /// AppendVec files contain sequential account records:
@@ -129,51 +391,30 @@ pub fn parse_appendvec(data: &[u8]) -> io::Result<Vec<SnapshotAccount>> {
     let mut accounts = Vec::new();
     let mut offset = 0;
 
-    const HEADER_SIZE: usize = 136;
-
-    while offset + HEADER_SIZE <= data.len() {
-        // Read 136-byte header
-        let write_version = u64::from_le_bytes(data[offset..offset + 0x08].try_into().unwrap());
-        let data_len =
-            u64::from_le_bytes(data[offset + 0x08..offset + 0x10].try_into().unwrap()) as usize;
-
-        let mut pubkey = [0u8; 32];
-        pubkey.copy_from_slice(&data[offset + 0x10..offset + 0x30]);
-
-        let lamports = u64::from_le_bytes(data[offset + 0x30..offset + 0x38].try_into().unwrap());
-        let rent_epoch = u64::from_le_bytes(data[offset + 0x38..offset + 0x40].try_into().unwrap());
-
-        let mut owner = [0u8; 32];
-        owner.copy_from_slice(&data[offset + 0x40..offset + 0x60]);
-
-        let executable = data[offset + 0x60] != 0;
-        // Skip 7 bytes padding at offset+0x61
-
-        let mut hash = [0u8; 32];
-        hash.copy_from_slice(&data[offset + 0x68..offset + 0x88]);
-
-        offset += HEADER_SIZE;
+    while offset + APPENDVEC_HEADER_SIZE <= data.len() {
+        let header = decode_appendvec_header(data, offset);
+        offset += APPENDVEC_HEADER_SIZE;
 
         // Read variable-length account data
-        if offset + data_len > data.len() {
+        if offset + header.data_len > data.len() {
             break; // Incomplete account
         }
 
-        let account_data = data[offset..offset + data_len].to_vec();
-        offset += data_len;
+        let account_data = data[offset..offset + header.data_len].to_vec();
+        offset += header.data_len;
 
         // 8-byte alignment padding
         let padding = (8 - (offset % 8)) % 8;
         offset += padding;
 
         accounts.push(SnapshotAccount {
-            write_version,
-            pubkey,
-            lamports,
-            rent_epoch,
-            owner,
-            executable,
-            hash,
+            write_version: header.write_version,
+            pubkey: header.pubkey.try_into().expect("pubkey is always 32 bytes"),
+            lamports: header.lamports,
+            rent_epoch: header.rent_epoch,
+            owner: header.owner.try_into().expect("owner is always 32 bytes"),
+            executable: header.executable,
+            hash: header.hash.try_into().expect("hash is always 32 bytes"),
             data: account_data,
         });
     }
@@ -199,39 +440,23 @@ impl<R: Read + 'static> Iterator for SnapshotAccountIterator<R> {
     type Item = io::Result<SnapshotAccount>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        const HEADER_SIZE: usize = 136;
-
         loop {
             // Try to parse next account from current AppendVec buffer
-            if self.current_offset + HEADER_SIZE <= self.current_appendvec.len() {
+            if self.current_offset + APPENDVEC_HEADER_SIZE <= self.current_appendvec.len() {
                 let data = &self.current_appendvec;
                 let offset = self.current_offset;
 
-                // Read 136-byte header
-                let write_version =
-                    u64::from_le_bytes(data[offset..offset + 0x08].try_into().unwrap());
-                let data_len =
-                    u64::from_le_bytes(data[offset + 0x08..offset + 0x10].try_into().unwrap())
-                        as usize;
-
-                let mut pubkey = [0u8; 32];
-                pubkey.copy_from_slice(&data[offset + 0x10..offset + 0x30]);
-
-                let lamports =
-                    u64::from_le_bytes(data[offset + 0x30..offset + 0x38].try_into().unwrap());
-                let rent_epoch =
-                    u64::from_le_bytes(data[offset + 0x38..offset + 0x40].try_into().unwrap());
-
-                let mut owner = [0u8; 32];
-                owner.copy_from_slice(&data[offset + 0x40..offset + 0x60]);
-
-                let executable = data[offset + 0x60] != 0;
-                // Skip 7 bytes padding at offset+0x61
+                let header = decode_appendvec_header(data, offset);
+                let write_version = header.write_version;
+                let pubkey: [u8; 32] = header.pubkey.try_into().expect("pubkey is always 32 bytes");
+                let lamports = header.lamports;
+                let rent_epoch = header.rent_epoch;
+                let owner: [u8; 32] = header.owner.try_into().expect("owner is always 32 bytes");
+                let executable = header.executable;
+                let hash: [u8; 32] = header.hash.try_into().expect("hash is always 32 bytes");
+                let data_len = header.data_len;
 
-                let mut hash = [0u8; 32];
-                hash.copy_from_slice(&data[offset + 0x68..offset + 0x88]);
-
-                self.current_offset += HEADER_SIZE;
+                self.current_offset += APPENDVEC_HEADER_SIZE;
 
                 // Read variable-length account data
                 if self.current_offset + data_len > data.len() {
@@ -315,9 +540,7 @@ impl<R: Read + 'static> Iterator for SnapshotAccountIterator<R> {
 pub fn stream_snapshot<P: AsRef<Path>>(
     path: P,
 ) -> io::Result<impl Iterator<Item = io::Result<SnapshotAccount>>> {
-    let file = File::open(path)?;
-    let buf_reader = BufReader::with_capacity(4 * 1024 * 1024, file); // 4MB buffer for streaming
-    let decoder = ZstdDecoder::new(buf_reader)?;
+    let decoder = open_archive_decoder(path, 4 * 1024 * 1024)?; // 4MB buffer for streaming
     let archive = Box::new(Archive::new(decoder));
 
     // SAFETY: Create self-referential struct - archive owns the data, entries borrow from it
@@ -341,141 +564,317 @@ pub fn parse_snapshot<P: AsRef<Path>>(path: P) -> io::Result<Vec<SnapshotAccount
     stream_snapshot(path)?.collect()
 }
 
-/// Fast snapshot extraction to directory
+/// Byte buffer allocated with 64-byte alignment, matching what the C++ SIMD account-data path
+/// requires
+///
+/// [`stream_snapshot_mapped`] copies each AppendVec segment into one of these exactly once (not
+/// once per account), so every [`AccountRef`] sliced out of it is guaranteed to start from a
+/// properly aligned base address.
 #[cfg(feature = "solana")]
-pub fn extract_snapshot<P: AsRef<Path>>(snapshot_path: P, output_dir: P) -> io::Result<u64> {
-    let file = File::open(snapshot_path)?;
-    let buf_reader = BufReader::with_capacity(1024 * 1024, file);
-    let decoder = ZstdDecoder::new(buf_reader)?;
-    let mut archive = Archive::new(decoder);
+struct AlignedBuffer {
+    ptr: std::ptr::NonNull<u8>,
+    len: usize,
+    layout: std::alloc::Layout,
+}
 
-    std::fs::create_dir_all(&output_dir)?;
-    archive.unpack(&output_dir)?;
+#[cfg(feature = "solana")]
+unsafe impl Send for AlignedBuffer {}
+#[cfg(feature = "solana")]
+unsafe impl Sync for AlignedBuffer {}
 
-    Ok(0) // Return account count if needed
+#[cfg(feature = "solana")]
+impl AlignedBuffer {
+    const ALIGNMENT: usize = 64;
+
+    fn copy_from(data: &[u8]) -> Self {
+        let len = data.len();
+        let layout = std::alloc::Layout::from_size_align(len.max(1), Self::ALIGNMENT)
+            .expect("AppendVec segment size fits within the allocator's limits");
+
+        // SAFETY: `layout` has a non-zero size and a power-of-two alignment.
+        let raw = unsafe { std::alloc::alloc(layout) };
+        let ptr = std::ptr::NonNull::new(raw).unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+
+        if len > 0 {
+            // SAFETY: `ptr` was just allocated with room for `len` bytes and doesn't overlap `data`.
+            unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.as_ptr(), len) };
+        }
+
+        Self { ptr, len, layout }
+    }
 }
 
-/// Parse manifest file to extract slot, epoch, and bank hash
-///
-/// The manifest is bincode-serialized BankFields. We extract key fields
-/// without requiring the full Solana SDK types.
 #[cfg(feature = "solana")]
-fn parse_manifest(data: &[u8]) -> io::Result<SnapshotManifest> {
-    // Manifest structure (simplified):
-    // - First 8 bytes: slot (u64)
-    // - Various fields...
-    // - Bank hash at a known offset
-    //
-    // Note: Full parsing requires matching exact Solana version's struct layout
-    // For now we extract what we can reliably
+impl std::ops::Deref for AlignedBuffer {
+    type Target = [u8];
 
-    if data.len() < 48 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "Manifest too small",
-        ));
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `ptr` is valid for `len` bytes for the lifetime of `self`.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
     }
+}
 
-    // Read slot (first u64 in most versions)
-    let slot = u64::from_le_bytes(data[0..8].try_into().unwrap());
+#[cfg(feature = "solana")]
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr`/`layout` are exactly what `std::alloc::alloc` returned in `copy_from`.
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
 
-    // Parent slot is typically next
-    let parent_slot = if data.len() >= 16 {
-        u64::from_le_bytes(data[8..16].try_into().unwrap())
-    } else {
-        0
-    };
+/// Zero-copy, borrowed view into an account record inside a [`MappedAppendVecBatch`]
+///
+/// Every field is a slice pointing directly into the batch's backing buffer - no per-account
+/// allocation, mirroring the "account data direct mapping" technique where the VM maps account
+/// bytes into address space via memory regions instead of copying. `pubkey`, `owner`, and `hash`
+/// are always 32 bytes; `data` is the account's variable-length payload.
+#[derive(Debug, Clone, Copy)]
+pub struct AccountRef<'a> {
+    pub write_version: u64,
+    pub pubkey: &'a [u8],
+    pub lamports: u64,
+    pub rent_epoch: u64,
+    pub owner: &'a [u8],
+    pub executable: bool,
+    pub hash: &'a [u8],
+    pub data: &'a [u8],
+}
 
-    // Try to find bank hash (32 bytes, usually early in the struct)
-    // This is a heuristic - exact offset varies by version
-    let mut bank_hash = [0u8; 32];
-    if data.len() >= 48 {
-        bank_hash.copy_from_slice(&data[16..48]);
+impl<'a> AccountRef<'a> {
+    /// Copies every borrowed field out of the backing segment into an owned [`SnapshotAccount`]
+    pub fn to_owned(&self) -> SnapshotAccount {
+        SnapshotAccount {
+            write_version: self.write_version,
+            pubkey: self.pubkey.try_into().expect("pubkey is always 32 bytes"),
+            lamports: self.lamports,
+            rent_epoch: self.rent_epoch,
+            owner: self.owner.try_into().expect("owner is always 32 bytes"),
+            executable: self.executable,
+            hash: self.hash.try_into().expect("hash is always 32 bytes"),
+            data: self.data.to_vec(),
+        }
     }
+}
 
-    // Epoch is harder to locate without full struct knowledge
-    // We'll set it to 0 and let users parse raw_data if needed
-    let epoch = 0;
-    let block_height = 0;
+/// Parse a single account record at `offset` as a borrowed [`AccountRef`], mirroring
+/// [`parse_appendvec_record`] but without copying `pubkey`/`owner`/`hash`/`data`
+#[cfg(feature = "solana")]
+fn parse_appendvec_record_ref(data: &[u8], offset: usize) -> AccountRef<'_> {
+    let header = decode_appendvec_header(data, offset);
+
+    let data_start = offset + APPENDVEC_HEADER_SIZE;
+    let account_data = &data[data_start..data_start + header.data_len];
+
+    AccountRef {
+        write_version: header.write_version,
+        pubkey: header.pubkey,
+        lamports: header.lamports,
+        rent_epoch: header.rent_epoch,
+        owner: header.owner,
+        executable: header.executable,
+        hash: header.hash,
+        data: account_data,
+    }
+}
 
-    Ok(SnapshotManifest {
-        slot,
-        bank_hash,
-        parent_slot,
-        epoch,
-        block_height,
-        raw_data: data.to_vec(),
-    })
+/// One AppendVec segment decompressed into a single 64-byte-aligned backing buffer, with every
+/// account record's offset already scanned
+///
+/// Every [`AccountRef`] produced by [`accounts`](Self::accounts) borrows from this batch's
+/// buffer, so the batch must outlive anything produced by iterating it - the whole point of this
+/// mode is that no per-account allocation happens after the batch itself is built.
+#[cfg(feature = "solana")]
+pub struct MappedAppendVecBatch {
+    buffer: AlignedBuffer,
+    offsets: Vec<usize>,
 }
 
-/// Full snapshot iterator that yields all data types
-pub struct FullSnapshotIterator<R: Read + 'static> {
+#[cfg(feature = "solana")]
+impl MappedAppendVecBatch {
+    /// Borrowed account views into this batch's segment, in on-disk order
+    pub fn accounts(&self) -> impl Iterator<Item = AccountRef<'_>> {
+        self.offsets.iter().map(move |&offset| parse_appendvec_record_ref(&self.buffer, offset))
+    }
+
+    /// Number of account records in this segment
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+/// Streaming iterator over [`MappedAppendVecBatch`]es, one per AppendVec segment in the archive
+#[cfg(feature = "solana")]
+pub struct MappedSnapshotIterator<R: Read + 'static> {
     tar_entries: tar::Entries<'static, R>,
-    current_appendvec: Vec<u8>,
-    current_offset: usize,
-    pending_items: Vec<SnapshotItem>,
     _archive: Box<Archive<R>>,
 }
 
 #[cfg(feature = "solana")]
-unsafe impl<R: Read + 'static> Send for FullSnapshotIterator<R> {}
+unsafe impl<R: Read + 'static> Send for MappedSnapshotIterator<R> {}
 
 #[cfg(feature = "solana")]
-impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
-    type Item = io::Result<SnapshotItem>;
+impl<R: Read + 'static> Iterator for MappedSnapshotIterator<R> {
+    type Item = io::Result<MappedAppendVecBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        const HEADER_SIZE: usize = 136;
+        loop {
+            let mut entry = match self.tar_entries.next()? {
+                Ok(e) => e,
+                Err(e) => return Some(Err(e)),
+            };
 
-        // Return pending items first (from non-account files)
-        if let Some(item) = self.pending_items.pop() {
-            return Some(Ok(item));
+            let path = match entry.path() {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            if !path.to_string_lossy().starts_with("accounts/") {
+                continue;
+            }
+
+            let mut raw = Vec::new();
+            if entry.read_to_end(&mut raw).is_err() {
+                continue;
+            }
+
+            let buffer = AlignedBuffer::copy_from(&raw);
+            let offsets = appendvec_record_offsets(&buffer);
+            return Some(Ok(MappedAppendVecBatch { buffer, offsets }));
         }
+    }
+}
 
-        loop {
-            // Try to parse next account from current AppendVec buffer
-            if self.current_offset + HEADER_SIZE <= self.current_appendvec.len() {
-                let data = &self.current_appendvec;
-                let offset = self.current_offset;
+/// Borrowing counterpart to [`stream_snapshot`]: yields one [`MappedAppendVecBatch`] per
+/// AppendVec segment instead of one owned [`SnapshotAccount`] per record
+///
+/// Each segment is decompressed into a single 64-byte-aligned backing buffer; every
+/// [`AccountRef`] handed out by that batch's [`accounts`](MappedAppendVecBatch::accounts) then
+/// borrows directly from it with no further allocation, avoiding the per-account `Vec<u8>` copy
+/// `stream_snapshot` pays for `data`. Callers that need to keep an account past the batch's
+/// lifetime (e.g. to stash it in a collection) can call [`AccountRef::to_owned`].
+///
+/// ```ignore
+/// use limcode::snapshot::stream_snapshot_mapped;
+///
+/// for batch in stream_snapshot_mapped("snapshot-123-aBcD.tar.zst")? {
+///     for account in batch?.accounts() {
+///         // account.data is a borrow into the batch's backing buffer - no copy
+///     }
+/// }
+/// ```
+#[cfg(feature = "solana")]
+pub fn stream_snapshot_mapped<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<impl Iterator<Item = io::Result<MappedAppendVecBatch>>> {
+    let decoder = open_archive_decoder(path, 4 * 1024 * 1024)?;
+    let archive = Box::new(Archive::new(decoder));
+
+    // SAFETY: Same self-referential pattern as `stream_snapshot` - archive owns the data,
+    // entries borrow from it. We convert to raw pointer, call entries(), then reconstruct the Box.
+    let archive_ptr = Box::into_raw(archive);
+    let entries = unsafe { (*archive_ptr).entries()? };
+    let archive = unsafe { Box::from_raw(archive_ptr) };
+
+    Ok(MappedSnapshotIterator {
+        #[allow(clippy::missing_transmute_annotations)]
+        tar_entries: unsafe { std::mem::transmute(entries) },
+        _archive: archive,
+    })
+}
 
-                let write_version =
-                    u64::from_le_bytes(data[offset..offset + 0x08].try_into().unwrap());
-                let data_len =
-                    u64::from_le_bytes(data[offset + 0x08..offset + 0x10].try_into().unwrap())
-                        as usize;
+/// Fixed-size account header fields, parsed without touching the variable-length `data` section
+///
+/// Handed to the predicate passed to [`stream_snapshot_filtered`] so it can decide whether an
+/// account is wanted before `data` is copied at all.
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, Copy)]
+pub struct AccountHeaderView {
+    pub pubkey: [u8; 32],
+    pub lamports: u64,
+    pub owner: [u8; 32],
+    pub executable: bool,
+    pub data_len: usize,
+}
 
-                let mut pubkey = [0u8; 32];
-                pubkey.copy_from_slice(&data[offset + 0x10..offset + 0x30]);
+/// Streaming iterator that decodes only the fixed-size header of each account, running `predicate`
+/// against it before deciding whether to copy `data`
+///
+/// Mirrors [`SnapshotAccountIterator`]'s cursor-advancement logic exactly, including the 8-byte
+/// alignment padding after `data`, so a rejected account still leaves the cursor at the start of
+/// the next record.
+#[cfg(feature = "solana")]
+pub struct FilteredSnapshotIterator<R: Read + 'static, F> {
+    tar_entries: tar::Entries<'static, R>,
+    current_appendvec: Vec<u8>,
+    current_offset: usize,
+    predicate: F,
+    _archive: Box<Archive<R>>,
+}
 
-                let lamports =
-                    u64::from_le_bytes(data[offset + 0x30..offset + 0x38].try_into().unwrap());
-                let rent_epoch =
-                    u64::from_le_bytes(data[offset + 0x38..offset + 0x40].try_into().unwrap());
+#[cfg(feature = "solana")]
+unsafe impl<R: Read + 'static, F> Send for FilteredSnapshotIterator<R, F> {}
 
-                let mut owner = [0u8; 32];
-                owner.copy_from_slice(&data[offset + 0x40..offset + 0x60]);
+#[cfg(feature = "solana")]
+impl<R: Read + 'static, F: Fn(&AccountHeaderView) -> bool> Iterator
+    for FilteredSnapshotIterator<R, F>
+{
+    type Item = io::Result<SnapshotAccount>;
 
-                let executable = data[offset + 0x60] != 0;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current_offset + APPENDVEC_HEADER_SIZE <= self.current_appendvec.len() {
+                let data = &self.current_appendvec;
+                let offset = self.current_offset;
 
-                let mut hash = [0u8; 32];
-                hash.copy_from_slice(&data[offset + 0x68..offset + 0x88]);
+                let header = decode_appendvec_header(data, offset);
+                let write_version = header.write_version;
+                let pubkey: [u8; 32] = header.pubkey.try_into().expect("pubkey is always 32 bytes");
+                let lamports = header.lamports;
+                let rent_epoch = header.rent_epoch;
+                let owner: [u8; 32] = header.owner.try_into().expect("owner is always 32 bytes");
+                let executable = header.executable;
+                let hash: [u8; 32] = header.hash.try_into().expect("hash is always 32 bytes");
+                let data_len = header.data_len;
 
-                self.current_offset += HEADER_SIZE;
+                self.current_offset += APPENDVEC_HEADER_SIZE;
 
                 if self.current_offset + data_len > data.len() {
+                    // Incomplete account, move to next file
                     self.current_appendvec.clear();
                     self.current_offset = 0;
                     continue;
                 }
 
+                let header = AccountHeaderView {
+                    pubkey,
+                    lamports,
+                    owner,
+                    executable,
+                    data_len,
+                };
+
+                if !(self.predicate)(&header) {
+                    // Rejected: skip the data copy but still advance the cursor past it so the
+                    // next account is found at the right position.
+                    self.current_offset += data_len;
+                    let padding = (8 - (self.current_offset % 8)) % 8;
+                    self.current_offset += padding;
+                    continue;
+                }
+
                 let account_data =
-                    data[self.current_offset..self.current_offset + data_len].to_vec();
+                    self.current_appendvec[self.current_offset..self.current_offset + data_len]
+                        .to_vec();
                 self.current_offset += data_len;
 
                 let padding = (8 - (self.current_offset % 8)) % 8;
                 self.current_offset += padding;
 
-                return Some(Ok(SnapshotItem::Account(SnapshotAccount {
+                return Some(Ok(SnapshotAccount {
                     write_version,
                     pubkey,
                     lamports,
@@ -484,18 +883,563 @@ impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
                     executable,
                     hash,
                     data: account_data,
-                })));
+                }));
             }
 
-            // Load next file from archive
-            let mut entry = match self.tar_entries.next()? {
-                Ok(e) => e,
-                Err(e) => return Some(Err(e)),
-            };
-
-            let path = match entry.path() {
-                Ok(p) => p.to_string_lossy().to_string(),
-                Err(_) => continue,
+            // Need to load next AppendVec file
+            loop {
+                let mut entry = match self.tar_entries.next()? {
+                    Ok(e) => e,
+                    Err(e) => return Some(Err(e)),
+                };
+
+                let path = match entry.path() {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+
+                let path_str = path.to_string_lossy();
+                if !path_str.starts_with("accounts/") {
+                    continue;
+                }
+
+                self.current_appendvec.clear();
+                if entry.read_to_end(&mut self.current_appendvec).is_err() {
+                    continue;
+                }
+
+                self.current_offset = 0;
+                break;
+            }
+        }
+    }
+}
+
+/// Stream accounts from a Solana snapshot archive, skipping the `data` copy for any account that
+/// fails `predicate`
+///
+/// `predicate` only sees the fixed-size [`AccountHeaderView`] (pubkey, lamports, owner,
+/// executable, data length) - rejected accounts never pay for the variable-length `data` copy
+/// [`stream_snapshot`] always performs. See [`by_owner`], [`executable_only`], and
+/// [`min_lamports`] for common predicates, or pass any closure.
+///
+/// ```ignore
+/// use limcode::snapshot::{stream_snapshot_filtered, executable_only};
+///
+/// for account in stream_snapshot_filtered("snapshot-123-aBcD.tar.zst", executable_only())? {
+///     let acc = account?; // only executable accounts were ever copied
+/// }
+/// ```
+#[cfg(feature = "solana")]
+pub fn stream_snapshot_filtered<P: AsRef<Path>, F: Fn(&AccountHeaderView) -> bool + 'static>(
+    path: P,
+    predicate: F,
+) -> io::Result<impl Iterator<Item = io::Result<SnapshotAccount>>> {
+    let decoder = open_archive_decoder(path, 4 * 1024 * 1024)?;
+    let archive = Box::new(Archive::new(decoder));
+
+    // SAFETY: Same self-referential pattern as `stream_snapshot` - archive owns the data,
+    // entries borrow from it. We convert to raw pointer, call entries(), then reconstruct the Box.
+    let archive_ptr = Box::into_raw(archive);
+    let entries = unsafe { (*archive_ptr).entries()? };
+    let archive = unsafe { Box::from_raw(archive_ptr) };
+
+    Ok(FilteredSnapshotIterator {
+        #[allow(clippy::missing_transmute_annotations)]
+        tar_entries: unsafe { std::mem::transmute(entries) },
+        current_appendvec: Vec::with_capacity(64 * 1024 * 1024),
+        current_offset: 0,
+        predicate,
+        _archive: archive,
+    })
+}
+
+/// Predicate for [`stream_snapshot_filtered`] that keeps only accounts owned by `owner`
+#[cfg(feature = "solana")]
+pub fn by_owner(owner: [u8; 32]) -> impl Fn(&AccountHeaderView) -> bool + Clone {
+    move |header: &AccountHeaderView| header.owner == owner
+}
+
+/// Predicate for [`stream_snapshot_filtered`] that keeps only executable accounts
+#[cfg(feature = "solana")]
+pub fn executable_only() -> impl Fn(&AccountHeaderView) -> bool + Clone {
+    |header: &AccountHeaderView| header.executable
+}
+
+/// Predicate for [`stream_snapshot_filtered`] that keeps only accounts with at least `min`
+/// lamports
+#[cfg(feature = "solana")]
+pub fn min_lamports(min: u64) -> impl Fn(&AccountHeaderView) -> bool + Clone {
+    move |header: &AccountHeaderView| header.lamports >= min
+}
+
+/// Fast snapshot extraction to directory
+#[cfg(feature = "solana")]
+pub fn extract_snapshot<P: AsRef<Path>>(snapshot_path: P, output_dir: P) -> io::Result<u64> {
+    let decoder = open_archive_decoder(snapshot_path, 1024 * 1024)?;
+    let mut archive = Archive::new(decoder);
+
+    std::fs::create_dir_all(&output_dir)?;
+    archive.unpack(&output_dir)?;
+
+    Ok(0) // Return account count if needed
+}
+
+/// Per-entry/total limits enforced by [`extract_snapshot_hardened`]
+#[derive(Debug, Clone, Copy)]
+pub struct UnpackLimits {
+    /// Abort once the running total of unpacked bytes across the whole archive exceeds this
+    pub max_unpacked_bytes: u64,
+    /// Abort if any single entry's declared size exceeds this
+    pub max_file_bytes: u64,
+}
+
+impl Default for UnpackLimits {
+    fn default() -> Self {
+        UnpackLimits {
+            max_unpacked_bytes: 500 * 1024 * 1024 * 1024, // 500 GiB
+            max_file_bytes: 64 * 1024 * 1024 * 1024,      // 64 GiB
+        }
+    }
+}
+
+/// Outcome of a call to [`extract_snapshot_hardened`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnpackStats {
+    pub entries_unpacked: u64,
+    pub total_bytes: u64,
+}
+
+/// `true` if `path` is a plain relative path with no `..`, root, or prefix component - the only
+/// shape that's guaranteed to stay under the output directory once joined onto it
+fn is_safe_relative_path(path: &Path) -> bool {
+    use std::path::Component;
+    path.components().next().is_some()
+        && path
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// `true` if `path`'s top-level component is one of the prefixes a legitimate snapshot archive
+/// entry can have - everything else is unexpected and rejected
+fn has_expected_prefix(path: &Path) -> bool {
+    if path == Path::new("version") || path == Path::new("status_cache") {
+        return true;
+    }
+    matches!(path.components().next(), Some(std::path::Component::Normal(p)) if p == "accounts" || p == "snapshots")
+}
+
+/// Extract a snapshot archive the way the runtime's `hardened_unpack` does before trusting a
+/// downloaded archive, rather than handing `tar::Archive::unpack` the whole thing unchecked like
+/// [`extract_snapshot`] does. Per entry this:
+///
+/// 1. Rejects absolute paths and any `..` component, so an entry can't escape `output_dir`
+/// 2. Enforces `limits.max_file_bytes` per entry and a running `limits.max_unpacked_bytes` total,
+///    guarding against decompression bombs
+/// 3. Rejects anything outside the `accounts/`, `snapshots/`, `version`, `status_cache`
+///    top-level prefixes a real snapshot archive is made of
+/// 4. Rejects any entry that isn't a regular file or a directory, so a symlink entry can't be
+///    planted and then walked through by a later entry's path to escape `output_dir` even though
+///    both entries individually pass the checks above
+///
+/// Returns how many entries were actually unpacked and how many bytes they totaled.
+#[cfg(feature = "solana")]
+pub fn extract_snapshot_hardened<P: AsRef<Path>>(
+    snapshot_path: P,
+    output_dir: P,
+    limits: UnpackLimits,
+) -> io::Result<UnpackStats> {
+    let decoder = open_archive_decoder(snapshot_path, 1024 * 1024)?;
+    let mut archive = Archive::new(decoder);
+
+    let output_dir = output_dir.as_ref();
+    std::fs::create_dir_all(output_dir)?;
+    let canonical_root = std::fs::canonicalize(output_dir)?;
+
+    let mut stats = UnpackStats::default();
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+
+        if !is_safe_relative_path(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("snapshot entry escapes output directory: {}", path.display()),
+            ));
+        }
+        if !has_expected_prefix(&path) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "unexpected snapshot entry outside accounts/snapshots/version/status_cache: {}",
+                    path.display()
+                ),
+            ));
+        }
+
+        let entry_type = entry.header().entry_type();
+        if entry_type != tar::EntryType::Regular && entry_type != tar::EntryType::Directory {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "snapshot entry {} has unsupported type {:?} (only regular files and directories are allowed)",
+                    path.display(),
+                    entry_type
+                ),
+            ));
+        }
+
+        let entry_size = entry.header().size()?;
+        if entry_size > limits.max_file_bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "snapshot entry {} exceeds max_file_bytes ({entry_size} > {})",
+                    path.display(),
+                    limits.max_file_bytes
+                ),
+            ));
+        }
+        stats.total_bytes += entry_size;
+        if stats.total_bytes > limits.max_unpacked_bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "snapshot archive exceeds max_unpacked_bytes ({} > {})",
+                    stats.total_bytes, limits.max_unpacked_bytes
+                ),
+            ));
+        }
+
+        let target = canonical_root.join(&path);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        entry.unpack(&target)?;
+        stats.entries_unpacked += 1;
+    }
+
+    Ok(stats)
+}
+
+/// `FeeCalculator`, mirrored field-for-field so [`BlockhashQueue`]/[`BankFieldsToDeserialize*`]
+/// decode at the right byte offsets
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FeeCalculator {
+    #[allow(dead_code)]
+    lamports_per_signature: u64,
+}
+
+/// `FeeRateGovernor`, mirrored the same way as [`FeeCalculator`]
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FeeRateGovernor {
+    #[allow(dead_code)]
+    target_lamports_per_signature: u64,
+    #[allow(dead_code)]
+    target_signatures_per_slot: u64,
+    #[allow(dead_code)]
+    min_lamports_per_signature: u64,
+    #[allow(dead_code)]
+    max_lamports_per_signature: u64,
+    #[allow(dead_code)]
+    burn_percent: u8,
+}
+
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct HashAge {
+    #[allow(dead_code)]
+    fee_calculator: FeeCalculator,
+    #[allow(dead_code)]
+    hash_index: u64,
+    #[allow(dead_code)]
+    timestamp: u64,
+}
+
+/// `BlockhashQueue`, one of the fields named explicitly in the incremental-snapshot request:
+/// a ring of recent blockhashes keyed by hash, each tagged with the fee calculator live when it
+/// was produced
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct BlockhashQueue {
+    #[allow(dead_code)]
+    last_hash_index: u64,
+    #[allow(dead_code)]
+    ages: std::collections::HashMap<[u8; 32], HashAge>,
+    #[allow(dead_code)]
+    max_age: usize,
+}
+
+/// Bank fields deserialized from a snapshot manifest, mirroring a byte-compatible *prefix* of
+/// the runtime's `BankFieldsToDeserialize` - current as of the `accounts_data_len` field being
+/// added around Solana 1.9 (see [`parse_manifest`]'s version dispatch).
+///
+/// Deliberately stops at `fee_rate_governor`: the fields after it (`collected_rent`,
+/// `rent_collector`, `epoch_schedule`, `inflation`, `stakes`, `epoch_stakes`, `is_delta`) pull in
+/// `Stakes<Delegation>`/`EpochStakes`, which in turn pull in `VoteAccount`/`VoteState` internals
+/// that are out of scope for this crate's snapshot reader. `bincode::deserialize` only reads as
+/// many fields as the target struct defines and ignores what follows, so this is safe to do as
+/// long as every field up to the stopping point is present and in the right order -
+/// `SnapshotManifest::raw_data` keeps the whole manifest available for callers who need to
+/// decode further themselves.
+#[cfg(feature = "solana")]
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct BankFieldsToDeserializeModern {
+    blockhash_queue: BlockhashQueue,
+    ancestors: std::collections::HashMap<u64, usize>,
+    hash: [u8; 32],
+    parent_hash: [u8; 32],
+    parent_slot: u64,
+    hard_forks: Vec<(u64, usize)>,
+    transaction_count: u64,
+    tick_height: u64,
+    signature_count: u64,
+    capitalization: u64,
+    max_tick_height: u64,
+    hashes_per_tick: Option<u64>,
+    ticks_per_slot: u64,
+    ns_per_slot: u128,
+    genesis_creation_time: i64,
+    slots_per_year: f64,
+    accounts_data_len: u64,
+    slot: u64,
+    epoch: u64,
+    block_height: u64,
+    collector_id: [u8; 32],
+    collector_fees: u64,
+    fee_calculator: FeeCalculator,
+    fee_rate_governor: FeeRateGovernor,
+}
+
+/// Same as [`BankFieldsToDeserializeModern`], for manifests from before `accounts_data_len` was
+/// added to `BankFieldsToDeserialize` (pre-1.9) - every other field lines up at the same offset
+#[cfg(feature = "solana")]
+#[allow(dead_code)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct BankFieldsToDeserializeLegacy {
+    blockhash_queue: BlockhashQueue,
+    ancestors: std::collections::HashMap<u64, usize>,
+    hash: [u8; 32],
+    parent_hash: [u8; 32],
+    parent_slot: u64,
+    hard_forks: Vec<(u64, usize)>,
+    transaction_count: u64,
+    tick_height: u64,
+    signature_count: u64,
+    capitalization: u64,
+    max_tick_height: u64,
+    hashes_per_tick: Option<u64>,
+    ticks_per_slot: u64,
+    ns_per_slot: u128,
+    genesis_creation_time: i64,
+    slots_per_year: f64,
+    slot: u64,
+    epoch: u64,
+    block_height: u64,
+    collector_id: [u8; 32],
+    collector_fees: u64,
+    fee_calculator: FeeCalculator,
+    fee_rate_governor: FeeRateGovernor,
+}
+
+#[cfg(feature = "solana")]
+impl From<BankFieldsToDeserializeModern> for SnapshotManifest {
+    fn from(fields: BankFieldsToDeserializeModern) -> Self {
+        SnapshotManifest {
+            slot: fields.slot,
+            bank_hash: fields.hash,
+            parent_hash: fields.parent_hash,
+            parent_slot: fields.parent_slot,
+            epoch: fields.epoch,
+            block_height: fields.block_height,
+            capitalization: fields.capitalization,
+            raw_data: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "solana")]
+impl From<BankFieldsToDeserializeLegacy> for SnapshotManifest {
+    fn from(fields: BankFieldsToDeserializeLegacy) -> Self {
+        SnapshotManifest {
+            slot: fields.slot,
+            bank_hash: fields.hash,
+            parent_hash: fields.parent_hash,
+            parent_slot: fields.parent_slot,
+            epoch: fields.epoch,
+            block_height: fields.block_height,
+            capitalization: fields.capitalization,
+            raw_data: Vec::new(),
+        }
+    }
+}
+
+/// Parse "major.minor" out of a version string like `"1.18.0"` or `"1.9.13 (src:abcd; feat:1)"`
+#[cfg(feature = "solana")]
+fn parse_major_minor(version: &str) -> Option<(u32, u32)> {
+    let core = version.split_whitespace().next()?;
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Parse manifest file to extract slot, epoch, block height, bank hash, parent hash, and
+/// capitalization
+///
+/// The manifest is `BankFieldsToDeserialize`, bincode-serialized with fixint encoding - the same
+/// thing `fields_from_streams`/`bank_from_streams` decode in the runtime. `version` (the
+/// snapshot's `version` file contents, if seen already) selects which struct shape to decode
+/// with, since the field list changed around Solana 1.9; an unrecognized or missing version
+/// falls back to the modern shape. If bincode decoding fails outright - an even older or newer
+/// layout this parser doesn't model - this falls back to the old byte-offset heuristic so callers
+/// still get a best-effort manifest rather than a hard error.
+#[cfg(feature = "solana")]
+fn parse_manifest(data: &[u8], version: Option<&str>) -> io::Result<SnapshotManifest> {
+    let use_modern_schema = version
+        .and_then(parse_major_minor)
+        .map(|major_minor| major_minor >= (1, 9))
+        .unwrap_or(true);
+
+    let decoded = if use_modern_schema {
+        bincode::deserialize::<BankFieldsToDeserializeModern>(data).map(SnapshotManifest::from)
+    } else {
+        bincode::deserialize::<BankFieldsToDeserializeLegacy>(data).map(SnapshotManifest::from)
+    };
+
+    match decoded {
+        Ok(mut manifest) => {
+            manifest.raw_data = data.to_vec();
+            Ok(manifest)
+        }
+        Err(_) => parse_manifest_heuristic(data),
+    }
+}
+
+/// Byte-offset heuristic fallback for manifest layouts [`parse_manifest`]'s bincode structs
+/// don't decode cleanly - the original implementation of this module's manifest parsing, kept
+/// as a best-effort path rather than failing outright
+#[cfg(feature = "solana")]
+fn parse_manifest_heuristic(data: &[u8]) -> io::Result<SnapshotManifest> {
+    if data.len() < 48 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Manifest too small",
+        ));
+    }
+
+    // Read slot (first u64 in most versions)
+    let slot = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+    // Parent slot is typically next
+    let parent_slot = if data.len() >= 16 {
+        u64::from_le_bytes(data[8..16].try_into().unwrap())
+    } else {
+        0
+    };
+
+    // Try to find bank hash (32 bytes, usually early in the struct)
+    // This is a heuristic - exact offset varies by version
+    let mut bank_hash = [0u8; 32];
+    if data.len() >= 48 {
+        bank_hash.copy_from_slice(&data[16..48]);
+    }
+
+    // Epoch, block height, capitalization, and parent hash aren't reliably locatable without
+    // full struct knowledge - left at their defaults; callers can parse `raw_data` if needed
+    Ok(SnapshotManifest {
+        slot,
+        bank_hash,
+        parent_hash: [0u8; 32],
+        parent_slot,
+        epoch: 0,
+        block_height: 0,
+        capitalization: 0,
+        raw_data: data.to_vec(),
+    })
+}
+
+/// Full snapshot iterator that yields all data types
+pub struct FullSnapshotIterator<R: Read + 'static> {
+    tar_entries: tar::Entries<'static, R>,
+    current_appendvec: Vec<u8>,
+    current_offset: usize,
+    pending_items: Vec<SnapshotItem>,
+    /// The archive's `version` file contents, once seen - `parse_manifest` uses this to pick
+    /// which `BankFieldsToDeserialize` shape to decode a manifest with
+    last_version: Option<String>,
+    _archive: Box<Archive<R>>,
+}
+
+#[cfg(feature = "solana")]
+unsafe impl<R: Read + 'static> Send for FullSnapshotIterator<R> {}
+
+#[cfg(feature = "solana")]
+impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
+    type Item = io::Result<SnapshotItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Return pending items first (from non-account files)
+        if let Some(item) = self.pending_items.pop() {
+            return Some(Ok(item));
+        }
+
+        loop {
+            // Try to parse next account from current AppendVec buffer
+            if self.current_offset + APPENDVEC_HEADER_SIZE <= self.current_appendvec.len() {
+                let data = &self.current_appendvec;
+                let offset = self.current_offset;
+
+                let header = decode_appendvec_header(data, offset);
+                let write_version = header.write_version;
+                let pubkey: [u8; 32] = header.pubkey.try_into().expect("pubkey is always 32 bytes");
+                let lamports = header.lamports;
+                let rent_epoch = header.rent_epoch;
+                let owner: [u8; 32] = header.owner.try_into().expect("owner is always 32 bytes");
+                let executable = header.executable;
+                let hash: [u8; 32] = header.hash.try_into().expect("hash is always 32 bytes");
+                let data_len = header.data_len;
+
+                self.current_offset += APPENDVEC_HEADER_SIZE;
+
+                if self.current_offset + data_len > data.len() {
+                    self.current_appendvec.clear();
+                    self.current_offset = 0;
+                    continue;
+                }
+
+                let account_data =
+                    data[self.current_offset..self.current_offset + data_len].to_vec();
+                self.current_offset += data_len;
+
+                let padding = (8 - (self.current_offset % 8)) % 8;
+                self.current_offset += padding;
+
+                return Some(Ok(SnapshotItem::Account(SnapshotAccount {
+                    write_version,
+                    pubkey,
+                    lamports,
+                    rent_epoch,
+                    owner,
+                    executable,
+                    hash,
+                    data: account_data,
+                })));
+            }
+
+            // Load next file from archive
+            let mut entry = match self.tar_entries.next()? {
+                Ok(e) => e,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let path = match entry.path() {
+                Ok(p) => p.to_string_lossy().to_string(),
+                Err(_) => continue,
             };
 
             // Read file content
@@ -508,6 +1452,7 @@ impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
             if path == "version" {
                 // Version file - simple text
                 let version = String::from_utf8_lossy(&content).trim().to_string();
+                self.last_version = Some(version.clone());
                 return Some(Ok(SnapshotItem::Version(version)));
             } else if path == "status_cache" {
                 // Status cache - raw binary
@@ -518,7 +1463,7 @@ impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
                 let parts: Vec<&str> = path.split('/').collect();
                 // Format: snapshots/SLOT/SLOT - the filename should match the slot number
                 if parts.len() == 3 && parts[1] == parts[2] && !content.is_empty() {
-                    match parse_manifest(&content) {
+                    match parse_manifest(&content, self.last_version.as_deref()) {
                         Ok(manifest) => return Some(Ok(SnapshotItem::Manifest(manifest))),
                         Err(_) => {
                             // If manifest parsing fails, return raw data
@@ -580,9 +1525,7 @@ impl<R: Read + 'static> Iterator for FullSnapshotIterator<R> {
 pub fn stream_snapshot_full<P: AsRef<Path>>(
     path: P,
 ) -> io::Result<impl Iterator<Item = io::Result<SnapshotItem>>> {
-    let file = File::open(path)?;
-    let buf_reader = BufReader::with_capacity(4 * 1024 * 1024, file);
-    let decoder = ZstdDecoder::new(buf_reader)?;
+    let decoder = open_archive_decoder(path, 4 * 1024 * 1024)?;
     let archive = Box::new(Archive::new(decoder));
 
     let archive_ptr = Box::into_raw(archive);
@@ -595,18 +1538,113 @@ pub fn stream_snapshot_full<P: AsRef<Path>>(
         current_appendvec: Vec::with_capacity(64 * 1024 * 1024),
         current_offset: 0,
         pending_items: Vec::new(),
+        last_version: None,
         _archive: archive,
     })
 }
 
-/// Get snapshot statistics without loading all data into memory
+/// Parse the base slot out of an incremental snapshot archive's filename
+///
+/// Solana names incremental snapshots
+/// `incremental-snapshot-<base_slot>-<slot>-<hash>.tar.zst`, mirroring the runtime's
+/// `IncrementalSnapshotArchiveInfo` - the base slot isn't stored anywhere inside the archive
+/// itself, so this is the only place to recover it.
 #[cfg(feature = "solana")]
-pub fn snapshot_stats<P: AsRef<Path>>(path: P) -> io::Result<SnapshotStats> {
-    let mut stats = SnapshotStats::default();
+fn parse_incremental_base_slot(path: &Path) -> io::Result<u64> {
+    let file_name = path.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "incremental snapshot path has no file name",
+        )
+    })?;
+
+    let parts: Vec<&str> = file_name.split('-').collect();
+    if parts.len() < 4 || parts[0] != "incremental" || parts[1] != "snapshot" {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("not an incremental snapshot filename: {file_name}"),
+        ));
+    }
 
-    for item in stream_snapshot_full(path)? {
-        match item? {
-            SnapshotItem::Version(v) => stats.version = v,
+    parts[2].parse::<u64>().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid base slot in incremental snapshot filename: {file_name}"),
+        )
+    })
+}
+
+/// Stream all data from a Solana *incremental* snapshot archive
+///
+/// Incremental snapshots only contain AppendVecs written after a base slot - everything else
+/// about the archive format (version, status cache, manifest, accounts) is identical to a full
+/// snapshot, so this wraps [`stream_snapshot_full`] and retags its `Manifest` item as
+/// [`SnapshotItem::IncrementalManifest`] carrying the base slot parsed from the filename.
+///
+/// ```ignore
+/// use limcode::snapshot::{stream_incremental_snapshot, SnapshotItem};
+///
+/// for item in stream_incremental_snapshot("incremental-snapshot-100-200-aBcD.tar.zst")? {
+///     if let SnapshotItem::IncrementalManifest { base_slot, manifest } = item? {
+///         assert_eq!(base_slot, 100);
+///         println!("Incremental snapshot covers slots {}..={}", base_slot, manifest.slot);
+///     }
+/// }
+/// ```
+#[cfg(feature = "solana")]
+pub fn stream_incremental_snapshot<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<impl Iterator<Item = io::Result<SnapshotItem>>> {
+    let base_slot = parse_incremental_base_slot(path.as_ref())?;
+    let inner = stream_snapshot_full(path)?;
+
+    Ok(inner.map(move |item| {
+        item.map(|item| match item {
+            SnapshotItem::Manifest(manifest) => SnapshotItem::IncrementalManifest {
+                base_slot,
+                manifest,
+            },
+            other => other,
+        })
+    }))
+}
+
+/// Layer an incremental snapshot's accounts over a full snapshot's, keyed by pubkey
+///
+/// Incremental accounts generally supersede the full snapshot's (they were written later), but
+/// ties are broken the same way the runtime does: the account with the higher `write_version`
+/// wins, since `write_version` is a monotonic per-account-write counter and is exactly the
+/// tie-breaker `snapshot_utils` uses internally. This function does not itself check that the
+/// two snapshots are compatible - callers should compare an incremental archive's
+/// [`SnapshotItem::IncrementalManifest`] `base_slot` against the full snapshot's
+/// `Manifest(..).slot` first.
+#[cfg(feature = "solana")]
+pub fn merge_snapshots(
+    full: Vec<SnapshotAccount>,
+    incremental: Vec<SnapshotAccount>,
+) -> Vec<SnapshotAccount> {
+    use std::collections::HashMap;
+
+    let mut by_pubkey: HashMap<[u8; 32], SnapshotAccount> = HashMap::new();
+    for account in full.into_iter().chain(incremental) {
+        match by_pubkey.get(&account.pubkey) {
+            Some(existing) if existing.write_version >= account.write_version => {}
+            _ => {
+                by_pubkey.insert(account.pubkey, account);
+            }
+        }
+    }
+    by_pubkey.into_values().collect()
+}
+
+/// Get snapshot statistics without loading all data into memory
+#[cfg(feature = "solana")]
+pub fn snapshot_stats<P: AsRef<Path>>(path: P) -> io::Result<SnapshotStats> {
+    let mut stats = SnapshotStats::default();
+
+    for item in stream_snapshot_full(path)? {
+        match item? {
+            SnapshotItem::Version(v) => stats.version = v,
             SnapshotItem::Manifest(m) => {
                 stats.slot = m.slot;
                 stats.epoch = m.epoch;
@@ -629,42 +1667,490 @@ pub fn snapshot_stats<P: AsRef<Path>>(path: P) -> io::Result<SnapshotStats> {
     Ok(stats)
 }
 
+/// Offset/length window applied to an account's `data` before [`to_ui_account`] base64-encodes
+/// it, mirroring the `dataSlice` param Solana RPC's `getAccountInfo`/`getProgramAccounts` accept -
+/// the same windowing a client uses to pull just a BPF upgradeable program's code out of its
+/// programdata account instead of the whole (possibly multi-megabyte) blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiDataSlice {
+    pub offset: usize,
+    pub length: usize,
+}
+
+/// An account rendered into the same shape validators return from `getAccountInfo`/
+/// `getProgramAccounts` over JSON-RPC, produced by [`to_ui_account`]/[`stream_snapshot_ui`]
+///
+/// `pubkey`/`owner` are base58 (Solana's usual text encoding for a 32-byte key, same as
+/// [`bs58`] is already used for elsewhere in this module) and `data` is base64 with an
+/// `encoding` tag, matching the RPC's `UiAccountEncoding::Base64` response shape - the pieces
+/// a JSON serializer needs are already broken out as plain fields rather than requiring callers
+/// to special-case binary data the way `#[derive(Serialize)]` over `SnapshotAccount` directly
+/// would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UiAccount {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data: String,
+    pub encoding: &'static str,
+}
+
+/// Render one [`SnapshotAccount`] into its RPC-compatible [`UiAccount`] form
+///
+/// `data_slice` windows `account.data` before it's base64-encoded, clamped to the account's
+/// actual length the same way the RPC does for an out-of-range `dataSlice` rather than erroring -
+/// `None` encodes the account's data in full.
+pub fn to_ui_account(account: &SnapshotAccount, data_slice: Option<UiDataSlice>) -> UiAccount {
+    let data = match data_slice {
+        Some(slice) => {
+            let start = slice.offset.min(account.data.len());
+            let end = start.saturating_add(slice.length).min(account.data.len());
+            &account.data[start..end]
+        }
+        None => &account.data[..],
+    };
+
+    UiAccount {
+        pubkey: bs58::encode(account.pubkey).into_string(),
+        lamports: account.lamports,
+        owner: bs58::encode(account.owner).into_string(),
+        executable: account.executable,
+        rent_epoch: account.rent_epoch,
+        data: base64::engine::general_purpose::STANDARD.encode(data),
+        encoding: "base64",
+    }
+}
+
+/// Stream a snapshot archive directly into [`UiAccount`]s, skipping everything that isn't a
+/// readable account
+///
+/// Built on [`stream_snapshot_full`], filtering down to `SnapshotItem::Account` entries and
+/// rendering each with [`to_ui_account`]; a read error partway through the archive or a
+/// non-account entry is simply skipped rather than surfaced, since the whole point of this
+/// iterator is to hand back a flat, JSON-ready account stream. Callers that need to detect a
+/// truncated/corrupt archive should use [`stream_snapshot_full`] directly instead. This is what
+/// turns the account extractor into a drop-in offline indexer: pipe the iterator through
+/// `serde_json` (or any other serializer) to dump or diff snapshot account state without a
+/// separate conversion crate.
+#[cfg(feature = "solana")]
+pub fn stream_snapshot_ui<P: AsRef<Path>>(
+    path: P,
+    data_slice: Option<UiDataSlice>,
+) -> io::Result<impl Iterator<Item = UiAccount>> {
+    let items = stream_snapshot_full(path)?;
+    Ok(items.filter_map(move |item| match item {
+        Ok(SnapshotItem::Account(account)) => Some(to_ui_account(&account, data_slice)),
+        _ => None,
+    }))
+}
+
+/// Child-hash fan-out for [`verify_snapshot_hash`]'s Merkle fold, tracking Solana's own
+/// `MERKLE_FANOUT`
+pub const MERKLE_FANOUT: usize = 16;
+
+/// Result of [`verify_snapshot_hash`]: whether the recomputed accounts hash matches the
+/// manifest's `bank_hash`, plus both hashes for callers that want to log a mismatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotHashVerification {
+    pub passed: bool,
+    pub computed_hash: [u8; 32],
+    pub expected_hash: [u8; 32],
+}
+
+/// Per-account hash folded into [`verify_snapshot_hash`]'s Merkle tree
+///
+/// `blake3(lamports_le || rent_epoch_le || data || executable_byte || owner || pubkey)`, the same
+/// fields (and order) the runtime hashes an account's contents with.
+fn account_hash(account: &SnapshotAccount) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&account.lamports.to_le_bytes());
+    hasher.update(&account.rent_epoch.to_le_bytes());
+    hasher.update(&account.data);
+    hasher.update(&[account.executable as u8]);
+    hasher.update(&account.owner);
+    hasher.update(&account.pubkey);
+    *hasher.finalize().as_bytes()
+}
+
+/// Fold leaf hashes into a single root: group consecutive leaves into chunks of
+/// [`MERKLE_FANOUT`], hash each chunk's concatenated children, and repeat level-by-level until a
+/// single root remains. A chunk with only one hash in it (including the final, whole-level case)
+/// is promoted unchanged rather than re-hashed, since there's nothing to concatenate it with.
+fn merkle_root(leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves;
+    while level.len() > 1 {
+        level = level
+            .chunks(MERKLE_FANOUT)
+            .map(|chunk| {
+                if chunk.len() == 1 {
+                    chunk[0]
+                } else {
+                    let mut hasher = blake3::Hasher::new();
+                    for leaf in chunk {
+                        hasher.update(leaf);
+                    }
+                    *hasher.finalize().as_bytes()
+                }
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Recompute the accounts Merkle hash while streaming a snapshot and compare it against the
+/// manifest's `bank_hash`
+///
+/// Each account with nonzero lamports is hashed with [`account_hash`] (zero-lamport accounts are
+/// tombstones and excluded, matching the runtime's own accounts-hash calculation); the resulting
+/// `(pubkey, hash)` pairs are sorted by pubkey for a deterministic leaf order, then folded into a
+/// root with [`merkle_root`]. Unlike [`snapshot_stats`], which only sums lamports, this gives
+/// callers an actual integrity check against the bank hash recorded in the manifest.
+#[cfg(feature = "solana")]
+pub fn verify_snapshot_hash<P: AsRef<Path>>(path: P) -> io::Result<SnapshotHashVerification> {
+    let mut expected_hash = [0u8; 32];
+    let mut leaves: Vec<([u8; 32], [u8; 32])> = Vec::new();
+
+    for item in stream_snapshot_full(path)? {
+        match item? {
+            SnapshotItem::Manifest(manifest) => {
+                expected_hash = manifest.bank_hash;
+            }
+            SnapshotItem::Account(account) => {
+                if account.lamports == 0 {
+                    continue;
+                }
+                leaves.push((account.pubkey, account_hash(&account)));
+            }
+            _ => {}
+        }
+    }
+
+    leaves.sort_by_key(|(pubkey, _)| *pubkey);
+    let computed_hash = merkle_root(leaves.into_iter().map(|(_, hash)| hash).collect());
+
+    Ok(SnapshotHashVerification {
+        passed: computed_hash == expected_hash,
+        computed_hash,
+        expected_hash,
+    })
+}
+
+/// An account yielded by [`stream_snapshot_verified`], paired with whether its stored `hash`
+/// matched the hash [`account_hash`] recomputed from its (lamports, rent_epoch, data, executable,
+/// owner, pubkey) fields
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone)]
+pub struct VerifiedSnapshotAccount {
+    pub account: SnapshotAccount,
+    pub verified: bool,
+}
+
+/// Options for [`stream_snapshot_verified`]
+#[cfg(feature = "solana")]
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotOptions {
+    /// Worker threads for batch hash verification. `None` probes `available_parallelism()`,
+    /// mirroring [`crate::sigverify::verify_batch`]'s default.
+    pub threads: Option<usize>,
+    /// Recompute and check each account's stored hash. When `false`, no hashing happens at all
+    /// and every account is reported `verified: true`.
+    pub verify: bool,
+}
+
+#[cfg(feature = "solana")]
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self {
+            threads: None,
+            verify: true,
+        }
+    }
+}
+
+/// Accounts buffered before [`stream_snapshot_verified`] recomputes their hashes as one rayon
+/// batch, instead of one account at a time on the reader thread
+///
+/// Also the "pool only spins up above a batch-count threshold" cutoff from the module's design:
+/// a trailing, less-than-full batch (the common case for snapshots smaller than one batch, and
+/// always true of the last batch of any snapshot) is hashed sequentially on the calling thread
+/// instead of paying for a rayon pool.
+#[cfg(feature = "solana")]
+pub const VERIFY_BATCH_SIZE: usize = 256;
+
+/// Iterator behind [`stream_snapshot_verified`]: collects accounts from `inner` into
+/// [`VERIFY_BATCH_SIZE`]-sized batches, verifies each full batch's hashes in parallel on a rayon
+/// pool sized to `options.threads` (or `available_parallelism()`), and yields results in the
+/// original order via a sequential merge from a pending queue.
+#[cfg(feature = "solana")]
+struct VerifiedSnapshotIterator<I: Iterator<Item = io::Result<SnapshotAccount>>> {
+    inner: I,
+    options: SnapshotOptions,
+    pool: Option<rayon::ThreadPool>,
+    pending: std::collections::VecDeque<io::Result<VerifiedSnapshotAccount>>,
+    done: bool,
+}
+
+#[cfg(feature = "solana")]
+impl<I: Iterator<Item = io::Result<SnapshotAccount>>> VerifiedSnapshotIterator<I> {
+    fn pool(&mut self) -> &rayon::ThreadPool {
+        self.pool.get_or_insert_with(|| {
+            let threads = self.options.threads.unwrap_or_else(|| {
+                std::thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            });
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads.max(1))
+                .build()
+                .expect("building a rayon thread pool with a positive thread count cannot fail")
+        })
+    }
+
+    fn verify_sequential(account: SnapshotAccount) -> VerifiedSnapshotAccount {
+        let verified = account_hash(&account) == account.hash;
+        VerifiedSnapshotAccount { account, verified }
+    }
+}
+
+#[cfg(feature = "solana")]
+impl<I: Iterator<Item = io::Result<SnapshotAccount>>> Iterator for VerifiedSnapshotIterator<I> {
+    type Item = io::Result<VerifiedSnapshotAccount>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+            if self.done {
+                return None;
+            }
+
+            let mut batch = Vec::with_capacity(VERIFY_BATCH_SIZE);
+            while batch.len() < VERIFY_BATCH_SIZE {
+                match self.inner.next() {
+                    Some(Ok(account)) => batch.push(account),
+                    Some(Err(e)) => {
+                        self.done = true;
+                        self.pending
+                            .extend(batch.drain(..).map(|a| Ok(Self::verify_sequential(a))));
+                        self.pending.push_back(Err(e));
+                        break;
+                    }
+                    None => {
+                        self.done = true;
+                        break;
+                    }
+                }
+            }
+
+            if batch.is_empty() {
+                continue;
+            }
+
+            if !self.options.verify {
+                self.pending.extend(
+                    batch
+                        .into_iter()
+                        .map(|account| Ok(VerifiedSnapshotAccount { account, verified: true })),
+                );
+            } else if batch.len() == VERIFY_BATCH_SIZE {
+                let verified_flags: Vec<bool> = {
+                    use rayon::prelude::*;
+                    self.pool()
+                        .install(|| batch.par_iter().map(|a| account_hash(a) == a.hash).collect())
+                };
+                self.pending.extend(
+                    batch
+                        .into_iter()
+                        .zip(verified_flags)
+                        .map(|(account, verified)| Ok(VerifiedSnapshotAccount { account, verified })),
+                );
+            } else {
+                self.pending
+                    .extend(batch.into_iter().map(|a| Ok(Self::verify_sequential(a))));
+            }
+        }
+    }
+}
+
+/// Stream a snapshot's accounts the same way as [`stream_snapshot`], but additionally recompute
+/// and check each account's stored `hash` field
+///
+/// `test_real_snapshot` reads every account's stored `hash` but never checks it; this gives
+/// callers that integrity check without paying for it one account at a time on the reader
+/// thread. Accounts are buffered into [`VERIFY_BATCH_SIZE`]-sized batches and their hashes
+/// recomputed across a rayon thread pool (modeled on [`crate::sigverify::verify_batch_with_threads`]'s
+/// chunk-and-pool shape), with a sequential merge that preserves the original account order.
+/// `options.verify = false` skips hashing entirely, reporting every account `verified: true`;
+/// `options.threads` sizes the pool (`None` probes `available_parallelism()`).
+#[cfg(feature = "solana")]
+pub fn stream_snapshot_verified<P: AsRef<Path>>(
+    path: P,
+    options: SnapshotOptions,
+) -> io::Result<impl Iterator<Item = io::Result<VerifiedSnapshotAccount>>> {
+    Ok(VerifiedSnapshotIterator {
+        inner: stream_snapshot(path)?,
+        options,
+        pool: None,
+        pending: std::collections::VecDeque::new(),
+        done: false,
+    })
+}
+
+/// Decompress every `accounts/` entry in a snapshot archive into an `Arc<[u8]>`, one buffer per
+/// AppendVec file, in archive order
+#[cfg(all(feature = "solana", feature = "async"))]
+fn read_appendvec_buffers<P: AsRef<Path>>(
+    snapshot_path: P,
+) -> io::Result<Vec<std::sync::Arc<[u8]>>> {
+    let decoder = open_archive_decoder(snapshot_path, 4 * 1024 * 1024)?;
+    let mut archive = Archive::new(decoder);
+
+    let mut buffers = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        if !path.to_string_lossy().starts_with("accounts/") {
+            continue;
+        }
+        let mut buf = Vec::new();
+        entry.read_to_end(&mut buf)?;
+        buffers.push(std::sync::Arc::from(buf.into_boxed_slice()));
+    }
+    Ok(buffers)
+}
+
+/// Scan an AppendVec buffer once, sequentially, recording the byte offset of each account
+/// record's 136-byte header
+///
+/// AppendVec records are self-describing (the header carries `data_len`) but not independently
+/// locatable - a worker can only start parsing at a known record boundary, so this single pass
+/// has to run before any parallel decode begins. It's cheap: no account data is copied, only
+/// `data_len`/padding is walked to find the next offset.
+#[cfg(feature = "solana")]
+fn appendvec_record_offsets(data: &[u8]) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+
+    while offset + APPENDVEC_HEADER_SIZE <= data.len() {
+        let data_len =
+            u64::from_le_bytes(data[offset + 0x08..offset + 0x10].try_into().unwrap()) as usize;
+
+        if offset + APPENDVEC_HEADER_SIZE + data_len > data.len() {
+            break; // Incomplete account
+        }
+        offsets.push(offset);
+        offset += APPENDVEC_HEADER_SIZE + data_len;
+
+        let padding = (8 - (offset % 8)) % 8;
+        offset += padding;
+    }
+
+    offsets
+}
+
+/// Parse a single account record at an offset already known-good from [`appendvec_record_offsets`]
+#[cfg(all(feature = "solana", feature = "async"))]
+fn parse_appendvec_record(data: &[u8], offset: usize) -> SnapshotAccount {
+    let header = decode_appendvec_header(data, offset);
+
+    let data_start = offset + APPENDVEC_HEADER_SIZE;
+    let account_data = data[data_start..data_start + header.data_len].to_vec();
+
+    SnapshotAccount {
+        write_version: header.write_version,
+        pubkey: header.pubkey.try_into().expect("pubkey is always 32 bytes"),
+        lamports: header.lamports,
+        rent_epoch: header.rent_epoch,
+        owner: header.owner.try_into().expect("owner is always 32 bytes"),
+        executable: header.executable,
+        hash: header.hash.try_into().expect("hash is always 32 bytes"),
+        data: account_data,
+    }
+}
+
+/// A `{ index, divisions }` partition selector, mirroring the runtime's own `ParallelSelector`:
+/// worker `index` takes every item whose flat position is `≡ index (mod divisions)`, so the
+/// record list doesn't need to be pre-chunked into `divisions` contiguous slices up front
+#[cfg(all(feature = "solana", feature = "async"))]
+#[derive(Debug, Clone, Copy)]
+struct ParallelSelector {
+    index: usize,
+    divisions: usize,
+}
+
+#[cfg(all(feature = "solana", feature = "async"))]
+impl ParallelSelector {
+    fn should_include(&self, position: usize) -> bool {
+        position % self.divisions == self.index
+    }
+}
+
 /// Parallel snapshot processing (high throughput)
+///
+/// Each AppendVec file is decompressed once into a shared `Arc<[u8]>` buffer, then a cheap
+/// sequential pass records every account record's offset (record boundaries can't be found any
+/// other way - see [`appendvec_record_offsets`]). Those offsets are partitioned across
+/// `num_workers` with a [`ParallelSelector`] and decoded concurrently on a dedicated rayon pool
+/// sized to `num_workers`, so decompression and header parsing are no longer serialized through
+/// a single reader task the way they were before.
 #[cfg(all(feature = "solana", feature = "async"))]
 pub async fn process_snapshot_parallel<F, T>(
     snapshot_path: &str,
     processor: F,
-    _num_workers: usize,
+    num_workers: usize,
 ) -> io::Result<Vec<T>>
 where
     F: Fn(SnapshotAccount) -> T + Send + Sync + Clone + 'static,
     T: Send + 'static,
 {
-    use tokio::sync::mpsc;
-
-    let (tx, mut rx) = mpsc::channel(1000);
-
-    // Spawn reader task
+    let num_workers = num_workers.max(1);
     let snapshot_path = snapshot_path.to_string();
+
     tokio::task::spawn_blocking(move || {
-        let accounts = parse_snapshot(&snapshot_path)?;
-        for account in accounts {
-            let _ = tx.blocking_send(account);
+        let appendvecs = read_appendvec_buffers(&snapshot_path)?;
+
+        let mut records: Vec<(std::sync::Arc<[u8]>, usize)> = Vec::new();
+        for buf in &appendvecs {
+            for offset in appendvec_record_offsets(buf) {
+                records.push((std::sync::Arc::clone(buf), offset));
+            }
         }
-        Ok::<_, io::Error>(())
-    });
 
-    // Spawn worker tasks
-    let mut results = Vec::new();
-    while let Some(account) = rx.recv().await {
-        let processor = processor.clone();
-        let result = tokio::task::spawn_blocking(move || processor(account))
-            .await
-            .unwrap();
-        results.push(result);
-    }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_workers)
+            .build()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let results: Vec<T> = pool.install(|| {
+            use rayon::prelude::*;
+            (0..num_workers)
+                .into_par_iter()
+                .flat_map(|index| {
+                    let selector = ParallelSelector {
+                        index,
+                        divisions: num_workers,
+                    };
+                    records
+                        .iter()
+                        .enumerate()
+                        .filter(|(position, _)| selector.should_include(*position))
+                        .map(|(_, (buf, offset))| processor(parse_appendvec_record(buf, *offset)))
+                        .collect::<Vec<T>>()
+                })
+                .collect()
+        });
 
-    Ok(results)
+        Ok(results)
+    })
+    .await
+    .unwrap()
 }
 
 #[cfg(test)]
@@ -676,4 +2162,1038 @@ mod tests {
         // Ensure SnapshotAccount is efficiently sized
         assert!(std::mem::size_of::<SnapshotAccount>() < 256);
     }
+
+    #[cfg(feature = "solana")]
+    fn test_account(pubkey: u8, write_version: u64) -> SnapshotAccount {
+        SnapshotAccount {
+            write_version,
+            pubkey: [pubkey; 32],
+            lamports: 0,
+            rent_epoch: 0,
+            owner: [0u8; 32],
+            executable: false,
+            hash: [0u8; 32],
+            data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_archive_format_from_path_infers_suffix() {
+        assert_eq!(
+            ArchiveFormat::from_path("snapshot-200-aBcD.tar.zst").unwrap(),
+            ArchiveFormat::Zstd
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("snapshot-200-aBcD.tar.gz").unwrap(),
+            ArchiveFormat::Gzip
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("snapshot-200-aBcD.tar.bz2").unwrap(),
+            ArchiveFormat::Bzip2
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("snapshot-200-aBcD.tar.lz4").unwrap(),
+            ArchiveFormat::Lz4
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("snapshot-200-aBcD.tar").unwrap(),
+            ArchiveFormat::Tar
+        );
+        assert!(ArchiveFormat::from_path("snapshot-200-aBcD.zip").is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_incremental_base_slot_reads_the_filename_convention() {
+        let path = Path::new("incremental-snapshot-100-200-aBcD1234.tar.zst");
+        assert_eq!(parse_incremental_base_slot(path).unwrap(), 100);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_incremental_base_slot_rejects_a_full_snapshot_filename() {
+        let path = Path::new("snapshot-200-aBcD1234.tar.zst");
+        assert!(parse_incremental_base_slot(path).is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_merge_snapshots_prefers_incremental_for_new_accounts() {
+        let full = vec![test_account(1, 1)];
+        let incremental = vec![test_account(2, 1)];
+        let merged = merge_snapshots(full, incremental);
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_merge_snapshots_breaks_ties_on_highest_write_version() {
+        let full = vec![test_account(1, 5)];
+        let incremental = vec![test_account(1, 3)];
+        let merged = merge_snapshots(full, incremental);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].write_version, 5);
+
+        let full = vec![test_account(1, 3)];
+        let incremental = vec![test_account(1, 5)];
+        let merged = merge_snapshots(full, incremental);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].write_version, 5);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_account_hash_changes_with_every_hashed_field() {
+        let mut account = test_account(1, 1);
+        account.lamports = 100;
+        let base = account_hash(&account);
+
+        let mut lamports_changed = account.clone();
+        lamports_changed.lamports = 200;
+        assert_ne!(account_hash(&lamports_changed), base);
+
+        let mut rent_epoch_changed = account.clone();
+        rent_epoch_changed.rent_epoch = 7;
+        assert_ne!(account_hash(&rent_epoch_changed), base);
+
+        let mut data_changed = account.clone();
+        data_changed.data = vec![9, 9, 9];
+        assert_ne!(account_hash(&data_changed), base);
+
+        let mut executable_changed = account.clone();
+        executable_changed.executable = true;
+        assert_ne!(account_hash(&executable_changed), base);
+
+        let mut owner_changed = account.clone();
+        owner_changed.owner = [5u8; 32];
+        assert_ne!(account_hash(&owner_changed), base);
+
+        let mut pubkey_changed = account.clone();
+        pubkey_changed.pubkey = [6u8; 32];
+        assert_ne!(account_hash(&pubkey_changed), base);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_to_ui_account_base58_and_base64_encodes_every_field() {
+        let mut account = test_account(7, 1);
+        account.lamports = 42;
+        account.rent_epoch = 3;
+        account.owner = [9u8; 32];
+        account.executable = true;
+        account.data = vec![1, 2, 3, 4];
+
+        let ui = to_ui_account(&account, None);
+        assert_eq!(ui.pubkey, bs58::encode([7u8; 32]).into_string());
+        assert_eq!(ui.owner, bs58::encode([9u8; 32]).into_string());
+        assert_eq!(ui.lamports, 42);
+        assert_eq!(ui.rent_epoch, 3);
+        assert!(ui.executable);
+        assert_eq!(ui.encoding, "base64");
+        assert_eq!(
+            ui.data,
+            base64::engine::general_purpose::STANDARD.encode([1, 2, 3, 4])
+        );
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_to_ui_account_data_slice_windows_the_data_field() {
+        let mut account = test_account(1, 1);
+        account.data = (0..10u8).collect();
+
+        let ui = to_ui_account(&account, Some(UiDataSlice { offset: 2, length: 3 }));
+        assert_eq!(
+            ui.data,
+            base64::engine::general_purpose::STANDARD.encode([2, 3, 4])
+        );
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_to_ui_account_data_slice_clamps_an_out_of_range_window() {
+        let mut account = test_account(1, 1);
+        account.data = vec![1, 2, 3];
+
+        let ui = to_ui_account(&account, Some(UiDataSlice { offset: 2, length: 100 }));
+        assert_eq!(ui.data, base64::engine::general_purpose::STANDARD.encode([3]));
+
+        let ui = to_ui_account(&account, Some(UiDataSlice { offset: 100, length: 5 }));
+        assert_eq!(ui.data, base64::engine::general_purpose::STANDARD.encode([]));
+    }
+
+    /// Append one 136-byte-header AppendVec record for `pubkey`, matching the layout
+    /// `FullSnapshotIterator` itself parses (same fields `build_appendvec_buffer` produces, which
+    /// can't be reused here as it's gated behind the `async` feature this test doesn't need)
+    #[cfg(feature = "solana")]
+    fn append_account_record(buf: &mut Vec<u8>, pubkey: u8) {
+        let data = vec![pubkey; 3];
+        buf.extend_from_slice(&1u64.to_le_bytes()); // write_version
+        buf.extend_from_slice(&(data.len() as u64).to_le_bytes()); // data_len
+        buf.extend_from_slice(&[pubkey; 32]); // pubkey
+        buf.extend_from_slice(&42u64.to_le_bytes()); // lamports
+        buf.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+        buf.extend_from_slice(&[0u8; 32]); // owner
+        buf.push(0); // executable
+        buf.extend_from_slice(&[0u8; 7]); // padding
+        buf.extend_from_slice(&[0u8; 32]); // hash
+        buf.extend_from_slice(&data);
+        let padding = (8 - (buf.len() % 8)) % 8;
+        buf.extend(std::iter::repeat(0u8).take(padding));
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_ui_yields_only_accounts() {
+        let mut account_buf = Vec::new();
+        append_account_record(&mut account_buf, 1);
+        append_account_record(&mut account_buf, 2);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+
+        let dir = unique_temp_dir("stream_snapshot_ui");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let accounts: Vec<UiAccount> = stream_snapshot_ui(&path, None).unwrap().collect();
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].pubkey, bs58::encode([1u8; 32]).into_string());
+        assert_eq!(accounts[1].pubkey, bs58::encode([2u8; 32]).into_string());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_mapped_yields_borrowed_accounts_matching_owned_parse() {
+        let mut account_buf = Vec::new();
+        append_account_record(&mut account_buf, 1);
+        append_account_record(&mut account_buf, 2);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+
+        let dir = unique_temp_dir("stream_snapshot_mapped");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let owned: Vec<SnapshotAccount> = stream_snapshot(&path).unwrap().map(|r| r.unwrap()).collect();
+
+        let batches: Vec<MappedAppendVecBatch> =
+            stream_snapshot_mapped(&path).unwrap().map(|r| r.unwrap()).collect();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].len(), 2);
+
+        let mapped: Vec<SnapshotAccount> =
+            batches[0].accounts().map(|a| a.to_owned()).collect();
+
+        assert_eq!(mapped.len(), owned.len());
+        for (m, o) in mapped.iter().zip(owned.iter()) {
+            assert_eq!(m.pubkey, o.pubkey);
+            assert_eq!(m.lamports, o.lamports);
+            assert_eq!(m.data, o.data);
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_mapped_account_ref_fields_borrow_from_the_batch_buffer() {
+        let mut account_buf = Vec::new();
+        append_account_record(&mut account_buf, 7);
+        let archive = build_test_archive(&[("accounts/200.0", &account_buf)]);
+
+        let dir = unique_temp_dir("mapped_account_ref");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let batches: Vec<MappedAppendVecBatch> =
+            stream_snapshot_mapped(&path).unwrap().map(|r| r.unwrap()).collect();
+        let accounts: Vec<AccountRef<'_>> = batches[0].accounts().collect();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].pubkey, &[7u8; 32]);
+        assert_eq!(accounts[0].data, &[7u8; 3]);
+        assert!(!accounts[0].executable);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_mapped_on_an_empty_archive_yields_no_batches() {
+        let archive = build_test_archive(&[]);
+        let dir = unique_temp_dir("mapped_empty");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let batches: Vec<MappedAppendVecBatch> =
+            stream_snapshot_mapped(&path).unwrap().map(|r| r.unwrap()).collect();
+        assert!(batches.is_empty());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Like [`append_account_record`] but with configurable `lamports`/`owner`/`executable`, so
+    /// filtering predicates have something to actually discriminate on
+    #[cfg(feature = "solana")]
+    fn append_account_record_with(
+        buf: &mut Vec<u8>,
+        pubkey: u8,
+        lamports: u64,
+        owner: [u8; 32],
+        executable: bool,
+    ) {
+        let data = vec![pubkey; 3];
+        buf.extend_from_slice(&1u64.to_le_bytes()); // write_version
+        buf.extend_from_slice(&(data.len() as u64).to_le_bytes()); // data_len
+        buf.extend_from_slice(&[pubkey; 32]); // pubkey
+        buf.extend_from_slice(&lamports.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+        buf.extend_from_slice(&owner);
+        buf.push(executable as u8);
+        buf.extend_from_slice(&[0u8; 7]); // padding
+        buf.extend_from_slice(&[0u8; 32]); // hash
+        buf.extend_from_slice(&data);
+        let padding = (8 - (buf.len() % 8)) % 8;
+        buf.extend(std::iter::repeat(0u8).take(padding));
+    }
+
+    /// Like `append_account_record_with`, but writes `hash` as either the account's real
+    /// recomputed hash (`correct_hash = true`) or a deliberately wrong one, for exercising
+    /// `stream_snapshot_verified`.
+    #[cfg(feature = "solana")]
+    fn append_account_record_with_hash(buf: &mut Vec<u8>, pubkey: u8, lamports: u64, correct_hash: bool) {
+        let data = vec![pubkey; 3];
+        let account = SnapshotAccount {
+            write_version: 1,
+            pubkey: [pubkey; 32],
+            lamports,
+            rent_epoch: 0,
+            owner: [0u8; 32],
+            executable: false,
+            hash: [0u8; 32],
+            data: data.clone(),
+        };
+        let hash = if correct_hash {
+            account_hash(&account)
+        } else {
+            [0xFFu8; 32]
+        };
+
+        buf.extend_from_slice(&1u64.to_le_bytes()); // write_version
+        buf.extend_from_slice(&(data.len() as u64).to_le_bytes()); // data_len
+        buf.extend_from_slice(&[pubkey; 32]); // pubkey
+        buf.extend_from_slice(&lamports.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+        buf.extend_from_slice(&[0u8; 32]); // owner
+        buf.push(0); // executable
+        buf.extend_from_slice(&[0u8; 7]); // padding
+        buf.extend_from_slice(&hash);
+        buf.extend_from_slice(&data);
+        let padding = (8 - (buf.len() % 8)) % 8;
+        buf.extend(std::iter::repeat(0u8).take(padding));
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_verified_flags_correct_and_tampered_hashes() {
+        let mut account_buf = Vec::new();
+        append_account_record_with_hash(&mut account_buf, 1, 10, true);
+        append_account_record_with_hash(&mut account_buf, 2, 20, false);
+        append_account_record_with_hash(&mut account_buf, 3, 30, true);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("verified_small_batch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let results: Vec<VerifiedSnapshotAccount> =
+            stream_snapshot_verified(&path, SnapshotOptions::default())
+                .unwrap()
+                .map(|r| r.unwrap())
+                .collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].account.pubkey, [1u8; 32]);
+        assert!(results[0].verified);
+        assert_eq!(results[1].account.pubkey, [2u8; 32]);
+        assert!(!results[1].verified);
+        assert_eq!(results[2].account.pubkey, [3u8; 32]);
+        assert!(results[2].verified);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_verified_preserves_order_across_a_full_parallel_batch() {
+        let mut account_buf = Vec::new();
+        for i in 0..(VERIFY_BATCH_SIZE + 10) {
+            let pubkey = (i % 256) as u8;
+            append_account_record_with_hash(&mut account_buf, pubkey, i as u64, i % 7 != 0);
+        }
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("verified_full_batch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let unfiltered: Vec<SnapshotAccount> = stream_snapshot(&path).unwrap().map(|r| r.unwrap()).collect();
+        let results: Vec<VerifiedSnapshotAccount> =
+            stream_snapshot_verified(&path, SnapshotOptions { threads: Some(2), verify: true })
+                .unwrap()
+                .map(|r| r.unwrap())
+                .collect();
+
+        assert_eq!(results.len(), unfiltered.len());
+        for (expected, actual) in unfiltered.iter().zip(results.iter()) {
+            assert_eq!(expected.pubkey, actual.account.pubkey);
+            assert_eq!(expected.lamports, actual.account.lamports);
+        }
+        for (i, result) in results.iter().enumerate() {
+            assert_eq!(result.verified, i % 7 != 0);
+        }
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_verified_with_verify_disabled_skips_hashing() {
+        let mut account_buf = Vec::new();
+        append_account_record_with_hash(&mut account_buf, 1, 10, false);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("verified_disabled");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let results: Vec<VerifiedSnapshotAccount> = stream_snapshot_verified(
+            &path,
+            SnapshotOptions { threads: None, verify: false },
+        )
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].verified);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_filtered_min_lamports_skips_rejected_accounts_data_copy() {
+        let mut account_buf = Vec::new();
+        append_account_record_with(&mut account_buf, 1, 10, [0u8; 32], false);
+        append_account_record_with(&mut account_buf, 2, 1_000, [0u8; 32], false);
+        append_account_record_with(&mut account_buf, 3, 5, [0u8; 32], false);
+        append_account_record_with(&mut account_buf, 4, 2_000, [0u8; 32], false);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("filtered_min_lamports");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let accounts: Vec<SnapshotAccount> =
+            stream_snapshot_filtered(&path, min_lamports(100)).unwrap().map(|r| r.unwrap()).collect();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].pubkey, [2u8; 32]);
+        assert_eq!(accounts[1].pubkey, [4u8; 32]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_filtered_by_owner_finds_the_right_account_after_skipped_ones() {
+        let wanted_owner = [9u8; 32];
+        let mut account_buf = Vec::new();
+        append_account_record_with(&mut account_buf, 1, 1, [0u8; 32], false);
+        append_account_record_with(&mut account_buf, 2, 1, [1u8; 32], false);
+        append_account_record_with(&mut account_buf, 3, 1, wanted_owner, false);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("filtered_by_owner");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        // Confirm byte-offset accounting stays correct across two skipped accounts in a row: the
+        // third account's data (not some misaligned garbage) is what comes back.
+        let accounts: Vec<SnapshotAccount> =
+            stream_snapshot_filtered(&path, by_owner(wanted_owner)).unwrap().map(|r| r.unwrap()).collect();
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].pubkey, [3u8; 32]);
+        assert_eq!(accounts[0].data, vec![3u8; 3]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_stream_snapshot_filtered_executable_only_matches_unfiltered_subset() {
+        let mut account_buf = Vec::new();
+        append_account_record_with(&mut account_buf, 1, 1, [0u8; 32], true);
+        append_account_record_with(&mut account_buf, 2, 1, [0u8; 32], false);
+        append_account_record_with(&mut account_buf, 3, 1, [0u8; 32], true);
+
+        let archive = build_test_archive(&[("accounts/100.0", &account_buf)]);
+        let dir = unique_temp_dir("filtered_executable_only");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snapshot.tar.zst");
+        std::fs::write(&path, &archive).unwrap();
+
+        let all: Vec<SnapshotAccount> = stream_snapshot(&path).unwrap().map(|r| r.unwrap()).collect();
+        let expected: Vec<[u8; 32]> =
+            all.iter().filter(|a| a.executable).map(|a| a.pubkey).collect();
+
+        let filtered: Vec<SnapshotAccount> =
+            stream_snapshot_filtered(&path, executable_only()).unwrap().map(|r| r.unwrap()).collect();
+        let actual: Vec<[u8; 32]> = filtered.iter().map(|a| a.pubkey).collect();
+
+        assert_eq!(actual, expected);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_merkle_root_of_a_single_leaf_is_that_leaf() {
+        let leaf = [3u8; 32];
+        assert_eq!(merkle_root(vec![leaf]), leaf);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_merkle_root_of_empty_leaves_is_zeroed() {
+        assert_eq!(merkle_root(Vec::new()), [0u8; 32]);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_merkle_root_is_order_sensitive_and_deterministic() {
+        let leaves: Vec<[u8; 32]> = (0..20u8).map(|i| [i; 32]).collect();
+        let root_a = merkle_root(leaves.clone());
+        let root_b = merkle_root(leaves.clone());
+        assert_eq!(root_a, root_b);
+
+        let mut reordered = leaves;
+        reordered.swap(0, 1);
+        assert_ne!(merkle_root(reordered), root_a);
+    }
+
+    #[test]
+    fn test_is_safe_relative_path_rejects_traversal_and_absolute_paths() {
+        assert!(is_safe_relative_path(Path::new("accounts/100.0")));
+        assert!(!is_safe_relative_path(Path::new("../../etc/passwd")));
+        assert!(!is_safe_relative_path(Path::new("/etc/passwd")));
+        assert!(!is_safe_relative_path(Path::new("accounts/../../../etc/passwd")));
+    }
+
+    #[test]
+    fn test_has_expected_prefix_only_allows_known_top_level_entries() {
+        assert!(has_expected_prefix(Path::new("accounts/100.0")));
+        assert!(has_expected_prefix(Path::new("snapshots/100/100")));
+        assert!(has_expected_prefix(Path::new("version")));
+        assert!(has_expected_prefix(Path::new("status_cache")));
+        assert!(!has_expected_prefix(Path::new("../../etc/passwd")));
+        assert!(!has_expected_prefix(Path::new("unexpected_top_level")));
+    }
+
+    #[cfg(feature = "solana")]
+    fn build_test_archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut tar_bytes = Vec::new();
+        {
+            let mut builder = tar::Builder::new(&mut tar_bytes);
+            for (path, data) in entries {
+                // Write the raw name field directly (instead of `append_data`, which validates
+                // the path and rejects `..`) so traversal-attempt entries can be constructed for
+                // the rejection tests below.
+                let mut header = tar::Header::new_gnu();
+                let name_bytes = path.as_bytes();
+                header.as_mut_bytes()[0..name_bytes.len()].copy_from_slice(name_bytes);
+                header.set_size(data.len() as u64);
+                header.set_cksum();
+                builder.append(&header, *data).unwrap();
+            }
+            builder.finish().unwrap();
+        }
+        zstd::stream::encode_all(&tar_bytes[..], 0).unwrap()
+    }
+
+    #[cfg(feature = "solana")]
+    fn unique_temp_dir(label: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "limcode_snapshot_test_{label}_{:?}",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_extract_snapshot_hardened_rejects_path_traversal() {
+        let archive = build_test_archive(&[("../../etc/passwd", b"pwned")]);
+        let archive_path = unique_temp_dir("traversal").with_extension("tar.zst");
+        std::fs::write(&archive_path, &archive).unwrap();
+        let output_dir = unique_temp_dir("traversal_out");
+
+        let err = extract_snapshot_hardened(&archive_path, &output_dir, UnpackLimits::default())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let _ = std::fs::remove_file(&archive_path);
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_extract_snapshot_hardened_rejects_unexpected_top_level_entry() {
+        let archive = build_test_archive(&[("unexpected_file", b"hi")]);
+        let archive_path = unique_temp_dir("unexpected").with_extension("tar.zst");
+        std::fs::write(&archive_path, &archive).unwrap();
+        let output_dir = unique_temp_dir("unexpected_out");
+
+        let err = extract_snapshot_hardened(&archive_path, &output_dir, UnpackLimits::default())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let _ = std::fs::remove_file(&archive_path);
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_extract_snapshot_hardened_enforces_max_file_bytes() {
+        let archive = build_test_archive(&[("version", b"1.18.0")]);
+        let archive_path = unique_temp_dir("toobig").with_extension("tar.zst");
+        std::fs::write(&archive_path, &archive).unwrap();
+        let output_dir = unique_temp_dir("toobig_out");
+
+        let tiny_limits = UnpackLimits {
+            max_unpacked_bytes: 1000,
+            max_file_bytes: 1,
+        };
+        let err =
+            extract_snapshot_hardened(&archive_path, &output_dir, tiny_limits).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let _ = std::fs::remove_file(&archive_path);
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_extract_snapshot_hardened_unpacks_valid_entries() {
+        let archive = build_test_archive(&[
+            ("version", b"1.18.0"),
+            ("accounts/100.0", b"account-data"),
+        ]);
+        let archive_path = unique_temp_dir("valid").with_extension("tar.zst");
+        std::fs::write(&archive_path, &archive).unwrap();
+        let output_dir = unique_temp_dir("valid_out");
+
+        let stats =
+            extract_snapshot_hardened(&archive_path, &output_dir, UnpackLimits::default())
+                .unwrap();
+        assert_eq!(stats.entries_unpacked, 2);
+        assert_eq!(stats.total_bytes, 6 + 12);
+        assert_eq!(std::fs::read(output_dir.join("version")).unwrap(), b"1.18.0");
+        assert_eq!(
+            std::fs::read(output_dir.join("accounts/100.0")).unwrap(),
+            b"account-data"
+        );
+
+        let _ = std::fs::remove_file(&archive_path);
+        let _ = std::fs::remove_dir_all(&output_dir);
+    }
+
+    #[cfg(feature = "solana")]
+    fn build_symlink_escape_archive() -> Vec<u8> {
+        let mut tar_bytes = Vec::new();
+        {
+            let mut builder = tar::Builder::new(&mut tar_bytes);
+
+            let mut symlink_header = tar::Header::new_gnu();
+            let name = b"accounts/escape";
+            symlink_header.as_mut_bytes()[0..name.len()].copy_from_slice(name);
+            symlink_header.set_entry_type(tar::EntryType::Symlink);
+            symlink_header.set_size(0);
+            symlink_header.set_cksum();
+            builder
+                .append_link(&mut symlink_header, "accounts/escape", "../../outside")
+                .unwrap();
+
+            let data = b"pwned";
+            let mut file_header = tar::Header::new_gnu();
+            let file_name = b"accounts/escape/pwned.txt";
+            file_header.as_mut_bytes()[0..file_name.len()].copy_from_slice(file_name);
+            file_header.set_size(data.len() as u64);
+            file_header.set_cksum();
+            builder.append(&file_header, &data[..]).unwrap();
+
+            builder.finish().unwrap();
+        }
+        zstd::stream::encode_all(&tar_bytes[..], 0).unwrap()
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_extract_snapshot_hardened_rejects_a_symlink_entry() {
+        let archive = build_symlink_escape_archive();
+        let archive_path = unique_temp_dir("symlink").with_extension("tar.zst");
+        std::fs::write(&archive_path, &archive).unwrap();
+        let output_dir = unique_temp_dir("symlink_out");
+        let escape_dir = std::env::temp_dir().join("outside");
+        let _ = std::fs::remove_dir_all(&escape_dir);
+
+        let err = extract_snapshot_hardened(&archive_path, &output_dir, UnpackLimits::default())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(!escape_dir.join("pwned.txt").exists());
+
+        let _ = std::fs::remove_file(&archive_path);
+        let _ = std::fs::remove_dir_all(&output_dir);
+        let _ = std::fs::remove_dir_all(&escape_dir);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_archive_filename_recognizes_full_snapshots() {
+        let hash = bs58::encode([7u8; 32]).into_string();
+        let file_name = format!("snapshot-197452918-{hash}.tar.zst");
+        let info = parse_archive_filename(&file_name).unwrap();
+        assert_eq!(
+            info,
+            SnapshotArchiveInfo::Full {
+                slot: 197452918,
+                hash: [7u8; 32],
+                format: ArchiveFormat::Zstd,
+            }
+        );
+        assert_eq!(info.slot(), 197452918);
+        assert_eq!(info.format(), ArchiveFormat::Zstd);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_archive_filename_recognizes_incremental_snapshots() {
+        let hash = bs58::encode([9u8; 32]).into_string();
+        let file_name = format!("incremental-snapshot-100-200-{hash}.tar.gz");
+        let info = parse_archive_filename(&file_name).unwrap();
+        assert_eq!(
+            info,
+            SnapshotArchiveInfo::Incremental {
+                base_slot: 100,
+                slot: 200,
+                hash: [9u8; 32],
+                format: ArchiveFormat::Gzip,
+            }
+        );
+        assert_eq!(info.slot(), 200);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_archive_filename_rejects_unrecognized_names() {
+        assert!(parse_archive_filename("not-a-snapshot.tar.zst").is_err());
+        assert!(parse_archive_filename("snapshot-abc-hash.tar.zst").is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_archive_filename_rejects_invalid_base58_hash() {
+        // '0', 'O', 'I', 'l' are excluded from the base58 alphabet
+        assert!(parse_archive_filename("snapshot-1-0OIl.tar.zst").is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    fn test_bank_fields_modern() -> BankFieldsToDeserializeModern {
+        BankFieldsToDeserializeModern {
+            blockhash_queue: BlockhashQueue {
+                last_hash_index: 0,
+                ages: std::collections::HashMap::new(),
+                max_age: 300,
+            },
+            ancestors: std::collections::HashMap::new(),
+            hash: [7u8; 32],
+            parent_hash: [9u8; 32],
+            parent_slot: 41,
+            hard_forks: Vec::new(),
+            transaction_count: 0,
+            tick_height: 0,
+            signature_count: 0,
+            capitalization: 123_456_789,
+            max_tick_height: 0,
+            hashes_per_tick: None,
+            ticks_per_slot: 64,
+            ns_per_slot: 0,
+            genesis_creation_time: 0,
+            slots_per_year: 0.0,
+            accounts_data_len: 0,
+            slot: 42,
+            epoch: 5,
+            block_height: 41,
+            collector_id: [0u8; 32],
+            collector_fees: 0,
+            fee_calculator: FeeCalculator {
+                lamports_per_signature: 5000,
+            },
+            fee_rate_governor: FeeRateGovernor {
+                target_lamports_per_signature: 5000,
+                target_signatures_per_slot: 20_000,
+                min_lamports_per_signature: 5000,
+                max_lamports_per_signature: 100_000,
+                burn_percent: 50,
+            },
+        }
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_manifest_decodes_modern_schema_for_recent_version() {
+        let fields = test_bank_fields_modern();
+        let encoded = bincode::serialize(&fields).unwrap();
+
+        let manifest = parse_manifest(&encoded, Some("1.18.0")).unwrap();
+        assert_eq!(manifest.slot, 42);
+        assert_eq!(manifest.epoch, 5);
+        assert_eq!(manifest.block_height, 41);
+        assert_eq!(manifest.parent_slot, 41);
+        assert_eq!(manifest.bank_hash, [7u8; 32]);
+        assert_eq!(manifest.parent_hash, [9u8; 32]);
+        assert_eq!(manifest.capitalization, 123_456_789);
+        assert_eq!(manifest.raw_data, encoded);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_manifest_decodes_legacy_schema_for_old_version() {
+        let modern = test_bank_fields_modern();
+        let legacy = BankFieldsToDeserializeLegacy {
+            blockhash_queue: modern.blockhash_queue,
+            ancestors: modern.ancestors,
+            hash: modern.hash,
+            parent_hash: modern.parent_hash,
+            parent_slot: modern.parent_slot,
+            hard_forks: modern.hard_forks,
+            transaction_count: modern.transaction_count,
+            tick_height: modern.tick_height,
+            signature_count: modern.signature_count,
+            capitalization: modern.capitalization,
+            max_tick_height: modern.max_tick_height,
+            hashes_per_tick: modern.hashes_per_tick,
+            ticks_per_slot: modern.ticks_per_slot,
+            ns_per_slot: modern.ns_per_slot,
+            genesis_creation_time: modern.genesis_creation_time,
+            slots_per_year: modern.slots_per_year,
+            slot: modern.slot,
+            epoch: modern.epoch,
+            block_height: modern.block_height,
+            collector_id: modern.collector_id,
+            collector_fees: modern.collector_fees,
+            fee_calculator: modern.fee_calculator,
+            fee_rate_governor: modern.fee_rate_governor,
+        };
+        let encoded = bincode::serialize(&legacy).unwrap();
+
+        let manifest = parse_manifest(&encoded, Some("1.8.16")).unwrap();
+        assert_eq!(manifest.slot, 42);
+        assert_eq!(manifest.epoch, 5);
+        assert_eq!(manifest.block_height, 41);
+
+        // The modern schema has one extra u64 field (`accounts_data_len`) ahead of `slot`, so
+        // decoding a legacy-shaped buffer with the modern struct must not also succeed.
+        assert!(bincode::deserialize::<BankFieldsToDeserializeModern>(&encoded).is_err());
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_manifest_defaults_to_modern_schema_when_version_unknown() {
+        let fields = test_bank_fields_modern();
+        let encoded = bincode::serialize(&fields).unwrap();
+
+        let manifest = parse_manifest(&encoded, None).unwrap();
+        assert_eq!(manifest.slot, 42);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_manifest_falls_back_to_heuristic_on_undecodable_data() {
+        // 64 bytes of junk: too short/malformed to satisfy either bincode schema, but big enough
+        // for the byte-offset heuristic to produce a best-effort result.
+        let junk = vec![0xAAu8; 64];
+
+        let manifest = parse_manifest(&junk, Some("1.18.0")).unwrap();
+        assert_eq!(manifest.epoch, 0);
+        assert_eq!(manifest.block_height, 0);
+        assert_eq!(manifest.raw_data, junk);
+    }
+
+    #[cfg(feature = "solana")]
+    #[test]
+    fn test_parse_major_minor_handles_trailing_feature_text() {
+        assert_eq!(parse_major_minor("1.18.0"), Some((1, 18)));
+        assert_eq!(
+            parse_major_minor("1.9.13 (src:abcd; feat:1)"),
+            Some((1, 9))
+        );
+        assert_eq!(parse_major_minor("not-a-version"), None);
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    fn build_appendvec_buffer(records: &[(u8, u64)]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for &(pubkey, write_version) in records {
+            let data = vec![pubkey; 3];
+            buf.extend_from_slice(&write_version.to_le_bytes());
+            buf.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            buf.extend_from_slice(&[pubkey; 32]);
+            buf.extend_from_slice(&42u64.to_le_bytes()); // lamports
+            buf.extend_from_slice(&0u64.to_le_bytes()); // rent_epoch
+            buf.extend_from_slice(&[0u8; 32]); // owner
+            buf.push(0); // executable
+            buf.extend_from_slice(&[0u8; 7]); // padding
+            buf.extend_from_slice(&[0u8; 32]); // hash
+            buf.extend_from_slice(&data);
+            let padding = (8 - (buf.len() % 8)) % 8;
+            buf.extend(std::iter::repeat(0u8).take(padding));
+        }
+        buf
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[test]
+    fn test_appendvec_record_offsets_finds_every_record_boundary() {
+        let buf = build_appendvec_buffer(&[(1, 10), (2, 20), (3, 30)]);
+        let offsets = appendvec_record_offsets(&buf);
+        assert_eq!(offsets.len(), 3);
+        assert_eq!(offsets[0], 0);
+
+        for &offset in &offsets {
+            let account = parse_appendvec_record(&buf, offset);
+            assert_eq!(account.data, vec![account.pubkey[0]; 3]);
+        }
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[test]
+    fn test_appendvec_record_offsets_stops_at_an_incomplete_record() {
+        let mut buf = build_appendvec_buffer(&[(1, 10), (2, 20)]);
+        buf.truncate(160); // first record's 144 bytes, plus a partial second header
+        let offsets = appendvec_record_offsets(&buf);
+        assert_eq!(offsets, vec![0]);
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[test]
+    fn test_appendvec_record_offsets_skips_a_truncated_trailing_record_with_an_intact_header() {
+        // The second record's 136-byte header is fully present, but its data is cut short - the
+        // offset must not be recorded, or a later slice into `data_len` bytes would panic.
+        let mut buf = build_appendvec_buffer(&[(1, 10), (2, 20)]);
+        const HEADER_SIZE: usize = 136;
+        buf.truncate(144 + HEADER_SIZE + 1); // second record's header plus one byte of its data
+        let offsets = appendvec_record_offsets(&buf);
+        assert_eq!(offsets, vec![0]);
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[test]
+    fn test_parallel_selector_partitions_every_position_exactly_once() {
+        let divisions = 4;
+        let positions = 0..17;
+        let mut seen = vec![0u32; 17];
+
+        for index in 0..divisions {
+            let selector = ParallelSelector { index, divisions };
+            for position in positions.clone() {
+                if selector.should_include(position) {
+                    seen[position] += 1;
+                }
+            }
+        }
+
+        assert!(seen.iter().all(|&count| count == 1));
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[tokio::test]
+    async fn test_process_snapshot_parallel_visits_every_account_across_files() {
+        let dir = unique_temp_dir("process_snapshot_parallel");
+        std::fs::create_dir_all(&dir).unwrap();
+        let archive_path = dir.join("snapshot-1-11111111111111111111111111111111.tar");
+
+        let file = std::fs::File::create(&archive_path).unwrap();
+        let mut builder = tar::Builder::new(file);
+        for (name, records) in [
+            ("accounts/1.0", vec![(1u8, 10u64), (2, 20)]),
+            ("accounts/1.1", vec![(3u8, 30u64)]),
+        ] {
+            let data = build_appendvec_buffer(&records);
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            builder.append_data(&mut header, name, &data[..]).unwrap();
+        }
+        builder.finish().unwrap();
+
+        let results = process_snapshot_parallel(
+            archive_path.to_str().unwrap(),
+            |account| account.pubkey[0],
+            2,
+        )
+        .await
+        .unwrap();
+
+        let mut pubkeys = results;
+        pubkeys.sort();
+        assert_eq!(pubkeys, vec![1, 2, 3]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(all(feature = "solana", feature = "async"))]
+    #[tokio::test]
+    async fn test_process_snapshot_parallel_skips_a_truncated_trailing_record_without_panicking() {
+        let dir = unique_temp_dir("process_snapshot_parallel_truncated");
+        std::fs::create_dir_all(&dir).unwrap();
+        let archive_path = dir.join("snapshot-1-11111111111111111111111111111111.tar");
+
+        let mut data = build_appendvec_buffer(&[(1u8, 10u64), (2u8, 20u64)]);
+        const HEADER_SIZE: usize = 136;
+        data.truncate(144 + HEADER_SIZE + 1); // second record's header plus one byte of its data
+
+        let file = std::fs::File::create(&archive_path).unwrap();
+        let mut builder = tar::Builder::new(file);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder.append_data(&mut header, "accounts/1.0", &data[..]).unwrap();
+        builder.finish().unwrap();
+
+        let results = process_snapshot_parallel(
+            archive_path.to_str().unwrap(),
+            |account| account.pubkey[0],
+            2,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results, vec![1]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -0,0 +1,152 @@
+//! Zero-copy offset parsing for Solana's packed transaction wire format
+//!
+//! A validator verifying a batch of transactions needs the signature bytes, the first signer's
+//! pubkey, and the signed message region of each one - but deserializing the whole `Transaction`
+//! (accounts, instructions, and all) just to get there allocates for data the verifier never
+//! looks at. [`parse_tx_offsets`] instead walks the leading compact-u16 signature count, the
+//! fixed-size `MessageHeader`, and the compact-u16 account-key count by hand, returning
+//! [`TxOffsets`]: a handful of byte offsets/lengths into the caller's own buffer. This lets a
+//! caller feed many transactions through signature verification with no per-transaction
+//! allocation, the same way validators extract offsets up front rather than deserializing.
+//!
+//! The compact-u16 counts are decoded with [`crate::read_shortvec_len`] - the same canonical,
+//! non-minimal-encoding-rejecting decoder that backs [`crate::deserialize_shortvec`] and
+//! [`crate::Decoder::read_shortu16`] - so a transaction with a malformed length can't slip
+//! through here any more than it could through those.
+
+use crate::read_shortvec_len;
+
+/// Length in bytes of one ed25519 signature
+pub(crate) const SIGNATURE_LEN: usize = 64;
+
+/// Length in bytes of the fixed `num_required_signatures`/`num_readonly_signed`/
+/// `num_readonly_unsigned` header that opens every transaction's message
+pub(crate) const MESSAGE_HEADER_LEN: usize = 3;
+
+/// Length in bytes of one account pubkey
+pub(crate) const PUBKEY_LEN: usize = 32;
+
+/// Byte offsets and lengths of the parts of a packed transaction [`parse_tx_offsets`] locates,
+/// all relative to the start of the buffer it was given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxOffsets {
+    /// Offset of the first signature, right after the compact-u16 signature count
+    pub sig_offset: usize,
+    /// Offset of the first signer's pubkey - the first entry in the message's account-keys array
+    pub pubkey_offset: usize,
+    /// Offset of the signed message region (everything the signatures cover)
+    pub msg_offset: usize,
+    /// Length in bytes of the signed message region, running to the end of the buffer
+    pub msg_len: usize,
+    /// Number of signatures, decoded from the leading compact-u16 count
+    pub sig_count: usize,
+    /// Number of account keys, decoded from the message's compact-u16 account-key count - the
+    /// upper bound on how many of those keys a caller may treat as signer pubkeys
+    pub pubkey_count: usize,
+}
+
+/// Locate the signatures, first signer pubkey, and signed message region of a packed transaction
+/// without deserializing it
+///
+/// Returns `None` rather than panicking if any compact-u16 count is malformed (non-minimally
+/// encoded, too many bytes, too large) or if the buffer is truncated before any offset it
+/// computes - a batched verifier can treat `None` as "reject this transaction" and move on to the
+/// next one.
+pub fn parse_tx_offsets(data: &[u8]) -> Option<TxOffsets> {
+    let (sig_count, consumed) = read_shortvec_len(data).ok()?;
+    let sig_offset = consumed;
+    let sig_bytes = sig_count.checked_mul(SIGNATURE_LEN)?;
+    let msg_offset = sig_offset.checked_add(sig_bytes)?;
+    if msg_offset.checked_add(MESSAGE_HEADER_LEN)? > data.len() {
+        return None;
+    }
+
+    let account_key_count_offset = msg_offset + MESSAGE_HEADER_LEN;
+    let (account_key_count, consumed) = read_shortvec_len(&data[account_key_count_offset..]).ok()?;
+    let pubkey_offset = account_key_count_offset.checked_add(consumed)?;
+    let account_keys_bytes = account_key_count.checked_mul(PUBKEY_LEN)?;
+    if account_key_count == 0 || pubkey_offset.checked_add(account_keys_bytes)? > data.len() {
+        return None;
+    }
+
+    let msg_len = data.len() - msg_offset;
+    Some(TxOffsets {
+        sig_offset,
+        pubkey_offset,
+        msg_offset,
+        msg_len,
+        sig_count,
+        pubkey_count: account_key_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_packed_tx(sig_count: usize, account_key_count: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        crate::write_shortvec_len(sig_count, &mut out).unwrap();
+        out.extend(std::iter::repeat(0xAAu8).take(sig_count * SIGNATURE_LEN));
+
+        let message_start = out.len();
+        out.push(1); // num_required_signatures
+        out.push(0); // num_readonly_signed
+        out.push(1); // num_readonly_unsigned
+        crate::write_shortvec_len(account_key_count, &mut out).unwrap();
+        for i in 0..account_key_count {
+            out.extend(std::iter::repeat(i as u8).take(PUBKEY_LEN));
+        }
+        out.extend_from_slice(&[0xFF; 8]); // stand-in for the rest of the message
+        let _ = message_start;
+        out
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_locates_every_field_of_a_well_formed_transaction() {
+        let data = build_packed_tx(2, 3);
+        let offsets = parse_tx_offsets(&data).unwrap();
+
+        assert_eq!(offsets.sig_count, 2);
+        assert_eq!(offsets.sig_offset, 1);
+        assert_eq!(&data[offsets.sig_offset..offsets.sig_offset + 64], &[0xAAu8; 64][..]);
+        assert_eq!(offsets.msg_offset, 1 + 2 * SIGNATURE_LEN);
+        assert_eq!(offsets.msg_len, data.len() - offsets.msg_offset);
+        assert_eq!(&data[offsets.pubkey_offset..offsets.pubkey_offset + PUBKEY_LEN], &[0u8; PUBKEY_LEN][..]);
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_rejects_truncated_signatures() {
+        let mut data = build_packed_tx(2, 1);
+        data.truncate(1 + SIGNATURE_LEN);
+        assert!(parse_tx_offsets(&data).is_none());
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_rejects_truncated_message_header() {
+        let mut data = build_packed_tx(1, 1);
+        data.truncate(1 + SIGNATURE_LEN + 2);
+        assert!(parse_tx_offsets(&data).is_none());
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_rejects_zero_account_keys() {
+        let data = build_packed_tx(1, 0);
+        assert!(parse_tx_offsets(&data).is_none());
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_rejects_truncated_account_keys() {
+        let mut data = build_packed_tx(1, 2);
+        data.truncate(data.len() - PUBKEY_LEN);
+        assert!(parse_tx_offsets(&data).is_none());
+    }
+
+    #[test]
+    fn test_parse_tx_offsets_rejects_non_canonical_signature_count() {
+        // A signature count encoded with a needless trailing zero continuation byte.
+        let mut data = vec![0x80, 0x00];
+        data.extend(std::iter::repeat(0u8).take(SIGNATURE_LEN + MESSAGE_HEADER_LEN + 4));
+        assert!(parse_tx_offsets(&data).is_none());
+    }
+}
@@ -2,6 +2,8 @@
 /// Goal: Beat wincode performance through advanced techniques
 use std::mem::MaybeUninit;
 
+use crate::Endian;
+
 /// Strategy 1: MaybeUninit to avoid Vec initialization overhead
 #[inline(always)]
 pub fn serialize_maybe_uninit(data: &[u8]) -> Vec<u8> {
@@ -11,8 +13,11 @@ pub fn serialize_maybe_uninit(data: &[u8]) -> Vec<u8> {
         let mut buf: Vec<MaybeUninit<u8>> = Vec::with_capacity(total_len);
         let ptr = buf.as_mut_ptr() as *mut u8;
 
-        // Write length directly as u64 (little-endian)
-        *(ptr as *mut u64) = (data.len() as u64).to_le();
+        // Write length directly as u64 (little-endian). `ptr` only has `Vec<MaybeUninit<u8>>`'s
+        // byte alignment, not necessarily `u64`'s, so this must go through `write_unaligned`
+        // rather than a plain store - a direct `*(ptr as *mut u64) = ...` is UB on
+        // strict-alignment targets (ARMv6, some MIPS) even though it happens to work on x86.
+        std::ptr::write_unaligned(ptr as *mut u64, (data.len() as u64).to_le());
 
         // Write data
         std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
@@ -23,6 +28,43 @@ pub fn serialize_maybe_uninit(data: &[u8]) -> Vec<u8> {
     }
 }
 
+/// Copy `count` (`<= 8`) bytes from `src` to `dst` via a branch ladder of individually-sized
+/// `copy_nonoverlapping` calls (4 bytes, then 2, then 1) instead of one `count`-sized call
+///
+/// Ported from the `copy_nonoverlapping_small` technique in rustc's `SipHasher128`: a
+/// `copy_nonoverlapping` with a small runtime `count` (1-7) often can't be proven constant-size
+/// by the optimizer and lowers to an actual `memcpy` call, which dominates the cost for a payload
+/// this small. Splitting into power-of-two-sized copies guided by bit tests on `count` gives the
+/// optimizer fixed sizes it can inline as plain loads/stores.
+///
+/// # Safety
+///
+/// `src`/`dst` must each be valid for `count` bytes and must not overlap. `count` must be `<= 8`.
+#[inline(always)]
+unsafe fn copy_nonoverlapping_small(src: *const u8, dst: *mut u8, count: usize) {
+    debug_assert!(count <= 8);
+
+    if count == 8 {
+        std::ptr::copy_nonoverlapping(src, dst, 8);
+        return;
+    }
+
+    let mut i = 0;
+    if i + 3 < count {
+        std::ptr::copy_nonoverlapping(src.add(i), dst.add(i), 4);
+        i += 4;
+    }
+    if i + 1 < count {
+        std::ptr::copy_nonoverlapping(src.add(i), dst.add(i), 2);
+        i += 2;
+    }
+    if i < count {
+        *dst.add(i) = *src.add(i);
+        i += 1;
+    }
+    debug_assert_eq!(i, count);
+}
+
 /// Strategy 2: Stack allocation for small buffers (avoid heap entirely)
 #[inline(always)]
 pub fn serialize_stack_small<const N: usize>(data: &[u8]) -> Vec<u8> {
@@ -32,11 +74,15 @@ pub fn serialize_stack_small<const N: usize>(data: &[u8]) -> Vec<u8> {
         let mut stack_buf = MaybeUninit::<[u8; N]>::uninit();
         let ptr = stack_buf.as_mut_ptr() as *mut u8;
 
-        // Write length
-        *(ptr as *mut u64) = (data.len() as u64).to_le();
+        // Write length (unaligned store - see serialize_maybe_uninit for why)
+        std::ptr::write_unaligned(ptr as *mut u64, (data.len() as u64).to_le());
 
-        // Write data
-        std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
+        // Write data - the branch-ladder copy avoids a memcpy call for the common tiny-record case
+        if data.len() <= 8 {
+            copy_nonoverlapping_small(data.as_ptr(), ptr.add(8), data.len());
+        } else {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
+        }
 
         // Copy to Vec (single allocation + copy)
         let total_len = data.len() + 8;
@@ -80,46 +126,107 @@ pub fn serialize_direct_write(data: &[u8]) -> Vec<u8> {
     buf
 }
 
-/// Strategy 5: SIMD-optimized for small fixed sizes
-#[cfg(target_arch = "x86_64")]
+/// Strategy 7: byte-order-parameterized writer, for targets that can't rely on the length
+/// prefix always being read/written little-endian (the `Endian` this takes is the same choice
+/// `Encoder::with_endian` exposes for `write_u16`/`write_u32`/`write_u64`). Always goes through
+/// `ptr::write_unaligned`, same as `serialize_direct_write`.
 #[inline(always)]
-pub fn serialize_simd_64(data: &[u8]) -> Vec<u8> {
-    assert_eq!(data.len(), 64, "Must be exactly 64 bytes");
+pub fn serialize_with_endian(data: &[u8], endian: Endian) -> Vec<u8> {
+    let total_len = data.len() + 8;
+    let mut buf: Vec<u8> = Vec::with_capacity(total_len);
 
     unsafe {
-        let mut buf: Vec<u8> = Vec::with_capacity(72);
         let ptr: *mut u8 = buf.as_mut_ptr();
 
-        // Write length
-        *(ptr as *mut u64) = 64u64.to_le();
-
-        // SIMD copy (64 bytes = 4x 16-byte SIMD operations)
-        #[cfg(target_feature = "avx")]
-        {
-            use std::arch::x86_64::*;
-            let src = data.as_ptr();
-            let dst = ptr.add(8);
-
-            // Load and store 4x 16-byte chunks
-            let v0 = _mm_loadu_si128(src as *const __m128i);
-            let v1 = _mm_loadu_si128(src.add(16) as *const __m128i);
-            let v2 = _mm_loadu_si128(src.add(32) as *const __m128i);
-            let v3 = _mm_loadu_si128(src.add(48) as *const __m128i);
-
-            _mm_storeu_si128(dst as *mut __m128i, v0);
-            _mm_storeu_si128(dst.add(16) as *mut __m128i, v1);
-            _mm_storeu_si128(dst.add(32) as *mut __m128i, v2);
-            _mm_storeu_si128(dst.add(48) as *mut __m128i, v3);
-        }
+        let len = data.len() as u64;
+        let len = match endian {
+            Endian::Little => len.to_le(),
+            Endian::Big => len.to_be(),
+        };
+        std::ptr::write_unaligned(ptr as *mut u64, len);
 
-        #[cfg(not(target_feature = "avx"))]
-        {
-            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), 64);
-        }
+        std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
 
-        buf.set_len(72);
-        buf
+        buf.set_len(total_len);
     }
+
+    buf
+}
+
+/// `serialize_with_endian(data, Endian::Little)` - matches `serialize_direct_write`'s wire format
+#[inline(always)]
+pub fn serialize_le(data: &[u8]) -> Vec<u8> {
+    serialize_with_endian(data, Endian::Little)
+}
+
+/// `serialize_with_endian(data, Endian::Big)` - for interop with big-endian/network-byte-order
+/// wire formats
+#[inline(always)]
+pub fn serialize_be(data: &[u8]) -> Vec<u8> {
+    serialize_with_endian(data, Endian::Big)
+}
+
+/// Strategy 5: type-erased chunked copy, for any size - not just 64 bytes
+///
+/// The previous version of this strategy manually issued `_mm_loadu/storeu_si128` calls behind
+/// `#[cfg(target_feature = "avx")]`, which only worked for a hardcoded 64-byte input and only
+/// vectorized on x86_64 hosts built with AVX enabled. Copying through `[u8; 32]`/`[u8; 16]`/
+/// `[u8; 8]` array assignments instead gives LLVM a plain, portable loop it can auto-vectorize
+/// to whatever width the target actually supports, with no `#[cfg(target_feature)]` guard and
+/// no fixed-size restriction.
+#[inline(always)]
+pub fn serialize_chunked(data: &[u8]) -> Vec<u8> {
+    let total_len = data.len() + 8;
+    let mut buf: Vec<u8> = Vec::with_capacity(total_len);
+
+    unsafe {
+        let ptr: *mut u8 = buf.as_mut_ptr();
+
+        // Write length (unaligned store - see serialize_maybe_uninit for why)
+        std::ptr::write_unaligned(ptr as *mut u64, (data.len() as u64).to_le());
+
+        copy_chunked(ptr.add(8), data.as_ptr(), data.len());
+
+        buf.set_len(total_len);
+    }
+
+    buf
+}
+
+/// Copy `len` bytes from `src` to `dst` as a cascade of 32-, then 16-, then 8-byte array
+/// chunks, with any final remainder (under 8 bytes) copied byte-by-byte
+///
+/// # Safety
+///
+/// Same preconditions as `ptr::copy_nonoverlapping`: `src`/`dst` must each be valid for `len`
+/// bytes and must not overlap.
+#[inline(always)]
+unsafe fn copy_chunked(dst: *mut u8, src: *const u8, len: usize) {
+    let mut offset = copy_array_chunks::<32>(dst, src, len);
+    offset += copy_array_chunks::<16>(dst.add(offset), src.add(offset), len - offset);
+    offset += copy_array_chunks::<8>(dst.add(offset), src.add(offset), len - offset);
+
+    let remaining = len - offset;
+    if remaining > 0 {
+        std::ptr::copy_nonoverlapping(src.add(offset), dst.add(offset), remaining);
+    }
+}
+
+/// Copy as many whole `[u8; N]` chunks as fit in `len` bytes, returning how many bytes were
+/// copied
+///
+/// # Safety
+///
+/// Same preconditions as `copy_chunked`.
+#[inline(always)]
+unsafe fn copy_array_chunks<const N: usize>(dst: *mut u8, src: *const u8, len: usize) -> usize {
+    let count = len / N;
+    let dst_chunks = dst as *mut [u8; N];
+    let src_chunks = src as *const [u8; N];
+    for i in 0..count {
+        *dst_chunks.add(i) = *src_chunks.add(i);
+    }
+    count * N
 }
 
 /// Strategy 6: Pre-allocated thread-local buffer pool
@@ -142,7 +249,11 @@ pub fn serialize_pooled(data: &[u8]) -> Vec<u8> {
         unsafe {
             let ptr: *mut u8 = buf.as_mut_ptr();
             std::ptr::write_unaligned(ptr as *mut u64, (data.len() as u64).to_le());
-            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
+            if data.len() <= 8 {
+                copy_nonoverlapping_small(data.as_ptr(), ptr.add(8), data.len());
+            } else {
+                std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(8), data.len());
+            }
             buf.set_len(total_len);
         }
 
@@ -158,3 +269,116 @@ pub fn return_to_pool(buf: Vec<u8>) {
         }
     });
 }
+
+/// Strategy 9: LEB128 varint length prefix instead of a fixed 8-byte `u64` header
+///
+/// Every other strategy in this module spends 8 bytes on the length header regardless of
+/// payload size, which is pure overhead for the small records the stack/pool paths exist to
+/// optimize (a 120-byte record pays a 7% tax; a 1-byte record pays 800%). LEB128 (7 payload bits
+/// per byte, high bit marks "more bytes follow") needs only 1 byte for any length under 128.
+///
+/// Borrows the eager-write-then-copy-once shape from rustc's integer `Display` impl: emit bytes
+/// into a fixed `[MaybeUninit<u8>; 10]` stack scratch buffer (10 is the max LEB128 length for a
+/// `u64`), track how many were written, then build the final `Vec` with one `with_capacity` and
+/// two `copy_nonoverlapping` calls. Unlike `Display`, which fills its scratch buffer from the end
+/// (digits are generated least-significant-first but must be read most-significant-first),
+/// varint bytes are generated in the same order they're written to the wire, so this fills from
+/// the start.
+#[inline(always)]
+pub fn serialize_varint(data: &[u8]) -> Vec<u8> {
+    let mut len_buf = [MaybeUninit::<u8>::uninit(); 10];
+    let len_bytes = write_varint_len(data.len() as u64, &mut len_buf);
+
+    let total_len = len_bytes.len() + data.len();
+    let mut buf = Vec::with_capacity(total_len);
+
+    unsafe {
+        let ptr = buf.as_mut_ptr();
+        std::ptr::copy_nonoverlapping(len_bytes.as_ptr(), ptr, len_bytes.len());
+        std::ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(len_bytes.len()), data.len());
+        buf.set_len(total_len);
+    }
+
+    buf
+}
+
+/// Emit `value` as LEB128 into `buf`, returning the filled prefix
+fn write_varint_len(mut value: u64, buf: &mut [MaybeUninit<u8>; 10]) -> &[u8] {
+    let mut i = 0;
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf[i].write(byte);
+        i += 1;
+        if value == 0 {
+            break;
+        }
+    }
+    // SAFETY: the first `i` slots were just written above.
+    unsafe { std::slice::from_raw_parts(buf.as_ptr() as *const u8, i) }
+}
+
+/// Read the LEB128 length prefix written by `serialize_varint`, returning the payload that
+/// follows it
+pub fn deserialize_varint(data: &[u8]) -> Result<&[u8], &'static str> {
+    let mut len: u64 = 0;
+    let mut shift = 0u32;
+    let mut i = 0;
+
+    loop {
+        let byte = *data
+            .get(i)
+            .ok_or("deserialize_varint: truncated length prefix")?;
+        len |= ((byte & 0x7f) as u64) << shift;
+        i += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err("deserialize_varint: length prefix exceeds 64 bits");
+        }
+    }
+
+    data.get(i..i + len as usize)
+        .ok_or("deserialize_varint: buffer too small for declared length")
+}
+
+/// Strategy 10: a `BufMut`-style sink so many frames can be appended to one growing buffer
+///
+/// Every strategy above returns a freshly allocated `Vec` per call, which is the right shape for
+/// one-off encodes but defeats batching when thousands of records are being packed into one
+/// outgoing buffer - each call pays its own allocation instead of amortizing one growth across
+/// the batch. Modeled on the `bytes` crate's `BufMut` trait: `serialize_into` writes a frame
+/// (8-byte little-endian length + payload, `serialize_bincode`'s format) through `put_u64_le`/
+/// `put_slice` instead of building its own `Vec`, so it works against any destination that can
+/// accept appended bytes - not just `Vec<u8>`, though that's the only implementor here.
+///
+/// This is unrelated to [`crate::io::FrameWriter`], which frames messages over a non-blocking
+/// `std::io::Write` destination rather than batching into an in-memory buffer.
+pub trait BufMut {
+    fn put_slice(&mut self, src: &[u8]);
+    fn put_u64_le(&mut self, value: u64);
+}
+
+impl BufMut for Vec<u8> {
+    #[inline(always)]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src);
+    }
+
+    #[inline(always)]
+    fn put_u64_le(&mut self, value: u64) {
+        self.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Write one length-prefixed frame into `dst`, appending rather than allocating a fresh buffer
+#[inline(always)]
+pub fn serialize_into<B: BufMut>(dst: &mut B, data: &[u8]) {
+    dst.put_u64_le(data.len() as u64);
+    dst.put_slice(data);
+}
@@ -0,0 +1,1062 @@
+//! Self-describing tagged format with a working `deserialize_any` and a `Value` type
+//!
+//! The compact format used by [`crate::serializer`]/[`crate::deserializer`] is bincode-style:
+//! every field is written at a fixed position with no type information, so `deserialize_any`
+//! has nothing to dispatch on and hard-errors. That's fine for known `struct`s, but it blocks
+//! decoding into `serde_json::Value`-style dynamic targets, `#[serde(flatten)]`, and untagged
+//! enums.
+//!
+//! This module adds an alternate, opt-in wire format (same approach as `serde_cbor`): every
+//! value is prefixed with a one-byte tag identifying its shape, so a decoder can figure out
+//! what's next without being told in advance. The two formats are NOT interchangeable - bytes
+//! written by [`crate::serializer::to_vec`] cannot be read by [`from_tagged_bytes`] and vice
+//! versa - so picking this format is an explicit choice via [`to_tagged_bytes`]/[`Value`]
+//! rather than a flag on the existing `Serializer`/`Deserializer`.
+
+use serde::de::{self, Deserialize, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize};
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_U8: u8 = 3;
+const TAG_U16: u8 = 4;
+const TAG_U32: u8 = 5;
+const TAG_U64: u8 = 6;
+const TAG_I8: u8 = 7;
+const TAG_I16: u8 = 8;
+const TAG_I32: u8 = 9;
+const TAG_I64: u8 = 10;
+const TAG_F32: u8 = 11;
+const TAG_F64: u8 = 12;
+const TAG_BYTES: u8 = 13;
+const TAG_STR: u8 = 14;
+const TAG_SEQ: u8 = 15;
+const TAG_MAP: u8 = 16;
+/// IEEE 754 binary16 (half-precision) float - decode-only, since no Rust primitive produces
+/// one: a `TaggedSerializer` never writes this tag, but `deserialize_any` widens it to `f32`
+/// for payloads produced by another half-precision-aware limcode implementation.
+const TAG_F16: u8 = 17;
+
+/// Error type for the tagged self-describing format
+#[derive(Debug)]
+pub enum Error {
+    Message(String),
+    Eof,
+    InvalidTag(u8),
+    Utf8Error(std::str::Utf8Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Message(msg) => write!(f, "{}", msg),
+            Error::Eof => write!(f, "unexpected end of input"),
+            Error::InvalidTag(tag) => write!(f, "invalid tagged-value marker byte: {}", tag),
+            Error::Utf8Error(e) => write!(f, "utf8 error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl From<std::str::Utf8Error> for Error {
+    fn from(e: std::str::Utf8Error) -> Self {
+        Error::Utf8Error(e)
+    }
+}
+
+/// A dynamically-typed value in the tagged self-describing format
+///
+/// Round-trips through [`to_value`]/[`from_value`] for any `T: Serialize`/`Deserialize`,
+/// mirroring how `serde_json::Value` is used as a dynamic intermediate representation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bytes(Vec<u8>),
+    Str(String),
+    Seq(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+}
+
+/// Serializer that writes the tagged self-describing format to an in-memory buffer
+pub struct TaggedSerializer {
+    buf: Vec<u8>,
+}
+
+impl TaggedSerializer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.buf
+    }
+
+    #[inline]
+    fn write_tag(&mut self, tag: u8) {
+        self.buf.push(tag);
+    }
+
+    #[inline]
+    fn write_len(&mut self, len: usize) {
+        self.buf.extend_from_slice(&(len as u64).to_le_bytes());
+    }
+}
+
+impl ser::Serializer for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.write_tag(if v { TAG_TRUE } else { TAG_FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.write_tag(TAG_I8);
+        self.buf.push(v as u8);
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.write_tag(TAG_I16);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.write_tag(TAG_I32);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.write_tag(TAG_I64);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.write_tag(TAG_U8);
+        self.buf.push(v);
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.write_tag(TAG_U16);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.write_tag(TAG_U32);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.write_tag(TAG_U64);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.write_tag(TAG_F32);
+        self.buf.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.write_tag(TAG_F64);
+        self.buf.extend_from_slice(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        let mut buf = [0u8; 4];
+        self.serialize_str(v.encode_utf8(&mut buf))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.write_tag(TAG_STR);
+        self.write_len(v.len());
+        self.buf.extend_from_slice(v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.write_tag(TAG_BYTES);
+        self.write_len(v.len());
+        self.buf.extend_from_slice(v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.write_tag(TAG_NULL);
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.write_tag(TAG_NULL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_u32(variant_index)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.write_tag(TAG_SEQ);
+        self.write_len(2);
+        self.serialize_u32(variant_index)?;
+        value.serialize(&mut *self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let len = len.ok_or_else(|| Error::Message("sequence length required".into()))?;
+        self.write_tag(TAG_SEQ);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.write_tag(TAG_SEQ);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.write_tag(TAG_SEQ);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.write_tag(TAG_SEQ);
+        self.write_len(2);
+        self.serialize_u32(variant_index)?;
+        self.write_tag(TAG_SEQ);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let len = len.ok_or_else(|| Error::Message("map length required".into()))?;
+        self.write_tag(TAG_MAP);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.write_tag(TAG_MAP);
+        self.write_len(len);
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.write_tag(TAG_SEQ);
+        self.write_len(2);
+        self.serialize_u32(variant_index)?;
+        self.write_tag(TAG_MAP);
+        self.write_len(len);
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::Serializer::serialize_str(&mut **self, key)?;
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut TaggedSerializer {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::Serializer::serialize_str(&mut **self, key)?;
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Deserializer that reads the tagged self-describing format from a borrowed byte slice
+pub struct TaggedDeserializer<'de> {
+    slice: &'de [u8],
+    pos: usize,
+}
+
+impl<'de> TaggedDeserializer<'de> {
+    pub fn new(slice: &'de [u8]) -> Self {
+        Self { slice, pos: 0 }
+    }
+
+    #[inline]
+    fn peek_tag(&self) -> Result<u8, Error> {
+        self.slice.get(self.pos).copied().ok_or(Error::Eof)
+    }
+
+    #[inline]
+    fn read_tag(&mut self) -> Result<u8, Error> {
+        let tag = self.peek_tag()?;
+        self.pos += 1;
+        Ok(tag)
+    }
+
+    #[inline]
+    fn read_raw(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.pos + len > self.slice.len() {
+            return Err(Error::Eof);
+        }
+        let bytes = &self.slice[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn read_fixed<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let bytes = self.read_raw(N)?;
+        let mut out = [0u8; N];
+        out.copy_from_slice(bytes);
+        Ok(out)
+    }
+
+    #[inline]
+    fn read_len(&mut self) -> Result<usize, Error> {
+        Ok(u64::from_le_bytes(self.read_fixed::<8>()?) as usize)
+    }
+
+    fn expect_tag(&mut self, expected: u8) -> Result<(), Error> {
+        let tag = self.read_tag()?;
+        if tag != expected {
+            return Err(Error::InvalidTag(tag));
+        }
+        Ok(())
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut TaggedDeserializer<'de> {
+    type Error = Error;
+
+    /// Dispatch purely on the leading tag byte - this is the entry point that the compact
+    /// format's `Deserializer` can't support, since it has no tag to dispatch on.
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.read_tag()? {
+            TAG_NULL => visitor.visit_unit(),
+            TAG_FALSE => visitor.visit_bool(false),
+            TAG_TRUE => visitor.visit_bool(true),
+            TAG_U8 => visitor.visit_u8(self.read_raw(1)?[0]),
+            TAG_U16 => visitor.visit_u16(u16::from_le_bytes(self.read_fixed::<2>()?)),
+            TAG_U32 => visitor.visit_u32(u32::from_le_bytes(self.read_fixed::<4>()?)),
+            TAG_U64 => visitor.visit_u64(u64::from_le_bytes(self.read_fixed::<8>()?)),
+            TAG_I8 => visitor.visit_i8(self.read_raw(1)?[0] as i8),
+            TAG_I16 => visitor.visit_i16(i16::from_le_bytes(self.read_fixed::<2>()?)),
+            TAG_I32 => visitor.visit_i32(i32::from_le_bytes(self.read_fixed::<4>()?)),
+            TAG_I64 => visitor.visit_i64(i64::from_le_bytes(self.read_fixed::<8>()?)),
+            TAG_F32 => visitor.visit_f32(f32::from_bits(u32::from_le_bytes(self.read_fixed::<4>()?))),
+            TAG_F64 => visitor.visit_f64(f64::from_bits(u64::from_le_bytes(self.read_fixed::<8>()?))),
+            TAG_F16 => {
+                let bits = u16::from_le_bytes(self.read_fixed::<2>()?);
+                visitor.visit_f32(crate::deserializer::f16_bits_to_f32(bits))
+            }
+            TAG_BYTES => {
+                let len = self.read_len()?;
+                let bytes = self.read_raw(len)?;
+                visitor.visit_borrowed_bytes(bytes)
+            }
+            TAG_STR => {
+                let len = self.read_len()?;
+                let bytes = self.read_raw(len)?;
+                visitor.visit_borrowed_str(std::str::from_utf8(bytes)?)
+            }
+            TAG_SEQ => {
+                let len = self.read_len()?;
+                visitor.visit_seq(TaggedSeqAccess { de: self, remaining: len })
+            }
+            TAG_MAP => {
+                let len = self.read_len()?;
+                visitor.visit_map(TaggedMapAccess { de: self, remaining: len })
+            }
+            tag => Err(Error::InvalidTag(tag)),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.peek_tag()? == TAG_NULL {
+            self.pos += 1;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.expect_tag(TAG_NULL)?;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(TaggedEnumAccess { de: self })
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_any(visitor)
+    }
+}
+
+struct TaggedSeqAccess<'a, 'de> {
+    de: &'a mut TaggedDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> SeqAccess<'de> for TaggedSeqAccess<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct TaggedMapAccess<'a, 'de> {
+    de: &'a mut TaggedDeserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de, 'a> MapAccess<'de> for TaggedMapAccess<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct TaggedEnumAccess<'a, 'de> {
+    de: &'a mut TaggedDeserializer<'de>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for TaggedEnumAccess<'a, 'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), Error> {
+        // Unit variants are written as a bare tagged u32; the others as a 2-element seq of
+        // [index, payload]. Either way the variant index is the next tagged value.
+        let is_seq = self.de.peek_tag()? == TAG_SEQ;
+        if is_seq {
+            self.de.pos += 1;
+            let _len = self.de.read_len()?;
+        }
+        self.de.expect_tag(TAG_U32)?;
+        let index = u32::from_le_bytes(self.de.read_fixed::<4>()?);
+        let v = seed.deserialize(de::value::U32Deserializer::<Error>::new(index))?;
+        Ok((v, self))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for TaggedEnumAccess<'a, 'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(&mut *self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(&mut *self.de, visitor)
+    }
+}
+
+/// Serialize a value to the tagged self-describing format
+#[inline]
+pub fn to_tagged_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    let mut serializer = TaggedSerializer::new(128);
+    value.serialize(&mut serializer)?;
+    Ok(serializer.into_vec())
+}
+
+/// Deserialize a value from the tagged self-describing format
+#[inline]
+pub fn from_tagged_bytes<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let mut deserializer = TaggedDeserializer::new(bytes);
+    T::deserialize(&mut deserializer)
+}
+
+impl Serialize for Value {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::U8(v) => serializer.serialize_u8(*v),
+            Value::U16(v) => serializer.serialize_u16(*v),
+            Value::U32(v) => serializer.serialize_u32(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::I8(v) => serializer.serialize_i8(*v),
+            Value::I16(v) => serializer.serialize_i16(*v),
+            Value::I32(v) => serializer.serialize_i32(*v),
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::F32(v) => serializer.serialize_f32(*v),
+            Value::F64(v) => serializer.serialize_f64(*v),
+            Value::Bytes(v) => serializer.serialize_bytes(v),
+            Value::Str(v) => serializer.serialize_str(v),
+            Value::Seq(items) => {
+                use ser::SerializeSeq;
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Value::Map(entries) => {
+                use ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (k, v) in entries {
+                    map.serialize_entry(k, v)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "any value representable in the tagged limcode format")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_u8<E: de::Error>(self, v: u8) -> Result<Value, E> {
+        Ok(Value::U8(v))
+    }
+
+    fn visit_u16<E: de::Error>(self, v: u16) -> Result<Value, E> {
+        Ok(Value::U16(v))
+    }
+
+    fn visit_u32<E: de::Error>(self, v: u32) -> Result<Value, E> {
+        Ok(Value::U32(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::U64(v))
+    }
+
+    fn visit_i8<E: de::Error>(self, v: i8) -> Result<Value, E> {
+        Ok(Value::I8(v))
+    }
+
+    fn visit_i16<E: de::Error>(self, v: i16) -> Result<Value, E> {
+        Ok(Value::I16(v))
+    }
+
+    fn visit_i32<E: de::Error>(self, v: i32) -> Result<Value, E> {
+        Ok(Value::I32(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::I64(v))
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Value, E> {
+        Ok(Value::F32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::F64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::Str(v.to_string()))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Value, E> {
+        Ok(Value::Str(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::Str(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Value, E> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Value, E> {
+        Ok(Value::Bytes(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Value, E> {
+        Ok(Value::Bytes(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Value::Map(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+/// Convert any serializable value into a dynamic [`Value`] tree
+///
+/// Implemented as a byte round-trip through the tagged format (serialize `T`, then decode the
+/// bytes as a `Value`) rather than a separate in-memory tree serializer, so it shares exactly
+/// one wire format with [`from_value`] and [`to_tagged_bytes`].
+pub fn to_value<T: Serialize>(value: &T) -> Result<Value, Error> {
+    let bytes = to_tagged_bytes(value)?;
+    from_tagged_bytes(&bytes)
+}
+
+/// Convert a dynamic [`Value`] tree back into any deserializable type
+pub fn from_value<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, Error> {
+    let bytes = to_tagged_bytes(&value)?;
+    from_tagged_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn test_primitives_round_trip() {
+        assert_eq!(to_value(&true).unwrap(), Value::Bool(true));
+        assert_eq!(to_value(&42u32).unwrap(), Value::U32(42));
+        assert_eq!(to_value(&-7i64).unwrap(), Value::I64(-7));
+        assert_eq!(to_value(&1.5f64).unwrap(), Value::F64(1.5));
+        assert_eq!(
+            to_value(&"hello".to_string()).unwrap(),
+            Value::Str("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_option_round_trips() {
+        assert_eq!(to_value(&None::<u32>).unwrap(), Value::Null);
+        assert_eq!(to_value(&Some(9u8)).unwrap(), Value::U8(9));
+    }
+
+    #[test]
+    fn test_seq_and_map_round_trip() {
+        let v = vec![1u32, 2, 3];
+        assert_eq!(
+            to_value(&v).unwrap(),
+            Value::Seq(vec![Value::U32(1), Value::U32(2), Value::U32(3)])
+        );
+
+        let mut m = std::collections::BTreeMap::new();
+        m.insert("a".to_string(), 1u32);
+        let value = to_value(&m).unwrap();
+        assert_eq!(
+            value,
+            Value::Map(vec![(Value::Str("a".to_string()), Value::U32(1))])
+        );
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn test_struct_round_trips_through_value_and_back() {
+        let point = Point { x: 3, y: -4 };
+        let value = to_value(&point).unwrap();
+        let restored: Point = from_value(value).unwrap();
+        assert_eq!(restored, point);
+    }
+
+    #[test]
+    fn test_deserialize_any_decodes_dynamic_target() {
+        let point = Point { x: 10, y: 20 };
+        let bytes = to_tagged_bytes(&point).unwrap();
+        let value: Value = from_tagged_bytes(&bytes).unwrap();
+        assert_eq!(
+            value,
+            Value::Map(vec![
+                (Value::Str("x".to_string()), Value::I32(10)),
+                (Value::Str("y".to_string()), Value::I32(20)),
+            ])
+        );
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    enum Shape {
+        Circle(f64),
+        Rect { w: f64, h: f64 },
+        Origin,
+    }
+
+    #[test]
+    fn test_enum_variants_round_trip() {
+        for shape in [
+            Shape::Circle(2.5),
+            Shape::Rect { w: 1.0, h: 2.0 },
+            Shape::Origin,
+        ] {
+            let bytes = to_tagged_bytes(&shape).unwrap();
+            let restored: Shape = from_tagged_bytes(&bytes).unwrap();
+            assert_eq!(restored, shape);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_any_widens_f16_tag() {
+        // tag byte 17 (TAG_F16) followed by the binary16 bit pattern for 1.0 (0x3c00)
+        let bytes = [17u8, 0x00, 0x3c];
+        let value: Value = from_tagged_bytes(&bytes).unwrap();
+        assert_eq!(value, Value::F32(1.0));
+    }
+}
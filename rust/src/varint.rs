@@ -0,0 +1,137 @@
+//! Per-field LEB128 variable-length integer encoding via a `VarInt<T>` wrapper
+//!
+//! Collection lengths already have a compact encoding (see `serialize_shortvec` in the
+//! crate root). `VarInt<T>` extends the same idea to individual integer fields - transaction
+//! counts, indices, lamport amounts - that are usually small but declared as a fixed-width
+//! type. Wrapping a field in `VarInt` makes a value below 2^14 serialize as 1-2 bytes instead of
+//! the full width (larger values take up to 3, 5 or 10 bytes for `u16`, `u32` or `u64`); the
+//! default fixed-width path (and bincode byte compatibility) is untouched for
+//! everything else, since this is purely opt-in per field.
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Wraps an unsigned integer so it (de)serializes as a 7-bit continuation varint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VarInt<T>(pub T);
+
+impl<T> From<T> for VarInt<T> {
+    fn from(value: T) -> Self {
+        VarInt(value)
+    }
+}
+
+struct VarIntVisitor<T>(PhantomData<T>);
+
+macro_rules! impl_varint {
+    ($ty:ty, $max_bytes:expr, $visit_fn:ident, $visit_ty:ty) => {
+        impl Serialize for VarInt<$ty> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut value = self.0 as u64;
+                let mut bytes = Vec::with_capacity($max_bytes);
+                loop {
+                    let byte = (value & 0x7f) as u8;
+                    value >>= 7;
+                    if value == 0 {
+                        bytes.push(byte);
+                        break;
+                    }
+                    bytes.push(byte | 0x80);
+                }
+
+                let mut tup = serializer.serialize_tuple(bytes.len())?;
+                for byte in &bytes {
+                    tup.serialize_element(byte)?;
+                }
+                tup.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for VarInt<$ty> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_tuple($max_bytes, VarIntVisitor::<$ty>(PhantomData))
+            }
+        }
+
+        impl<'de> Visitor<'de> for VarIntVisitor<$ty> {
+            type Value = VarInt<$ty>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a LEB128-encoded variable-length integer")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut result: u64 = 0;
+                let mut shift: u32 = 0;
+
+                loop {
+                    let byte: u8 = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::custom("unexpected end of varint stream"))?;
+
+                    if shift >= 64 || (shift == 63 && byte > 1) {
+                        return Err(de::Error::custom("varint overflows u64"));
+                    }
+
+                    result |= ((byte & 0x7f) as u64) << shift;
+                    if byte & 0x80 == 0 {
+                        break;
+                    }
+                    shift += 7;
+                }
+
+                <$ty>::try_from(result)
+                    .map(VarInt)
+                    .map_err(|_| de::Error::custom(concat!("varint overflows ", stringify!($ty))))
+            }
+
+            fn $visit_fn<E: de::Error>(self, _v: $visit_ty) -> Result<Self::Value, E> {
+                Err(de::Error::custom("VarInt expects a tuple of bytes"))
+            }
+        }
+    };
+}
+
+impl_varint!(u16, 3, visit_u16, u16);
+impl_varint!(u32, 5, visit_u32, u32);
+impl_varint!(u64, 10, visit_u64, u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T>(value: VarInt<T>)
+    where
+        T: std::fmt::Debug + PartialEq + Copy,
+        VarInt<T>: Serialize + for<'de> Deserialize<'de>,
+    {
+        let bytes = crate::serializer::to_vec(&value).unwrap();
+        let decoded: VarInt<T> = crate::deserializer::from_bytes(&bytes).unwrap();
+        assert_eq!(value, decoded);
+    }
+
+    #[test]
+    fn test_small_values_are_compact() {
+        let bytes = crate::serializer::to_vec(&VarInt(42u32)).unwrap();
+        assert_eq!(bytes.len(), 1);
+        round_trip(VarInt(42u32));
+    }
+
+    #[test]
+    fn test_multi_byte_values() {
+        round_trip(VarInt(300u32));
+        round_trip(VarInt(16383u32));
+        round_trip(VarInt(1_048_575u64));
+        round_trip(VarInt(u64::MAX));
+    }
+
+    #[test]
+    fn test_zero_and_boundaries() {
+        round_trip(VarInt(0u16));
+        round_trip(VarInt(u16::MAX));
+        round_trip(VarInt(127u64));
+        round_trip(VarInt(128u64));
+    }
+}
@@ -0,0 +1,74 @@
+//! End-to-end test for `#[derive(Encode, Decode, ColumnarPod)]` against `limcode::codec` and
+//! `limcode::bitpack`
+//!
+//! Requires the `derive` feature (`cargo test --features derive`).
+
+use limcode::bitpack::{deserialize_pod_struct_columnar, serialize_pod_struct_columnar};
+use limcode::codec::{decode, encode};
+use limcode::{ColumnarPod, Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Transaction {
+    amount: u64,
+    fee: u64,
+    memo: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+enum Instruction {
+    Transfer(u64),
+    CreateAccount { lamports: u64, owner: [u8; 4] },
+    CloseAccount,
+}
+
+#[test]
+fn test_derived_struct_round_trips() {
+    let tx = Transaction {
+        amount: 1000,
+        fee: 10,
+        memo: String::from("payment"),
+    };
+
+    let bytes = encode(&tx);
+    let decoded: Transaction = decode(&bytes).unwrap();
+    assert_eq!(tx, decoded);
+}
+
+#[test]
+fn test_derived_enum_round_trips_every_variant() {
+    let variants = vec![
+        Instruction::Transfer(42),
+        Instruction::CreateAccount {
+            lamports: 7,
+            owner: [1, 2, 3, 4],
+        },
+        Instruction::CloseAccount,
+    ];
+
+    for variant in variants {
+        let bytes = encode(&variant);
+        let decoded: Instruction = decode(&bytes).unwrap();
+        assert_eq!(variant, decoded);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, ColumnarPod)]
+struct Sample {
+    timestamp: u64,
+    offset: i32,
+    bucket: u8,
+    flagged: bool,
+}
+
+#[test]
+fn test_derived_columnar_pod_round_trips() {
+    let samples = [
+        Sample { timestamp: 1_700_000_000, offset: -3, bucket: 1, flagged: true },
+        Sample { timestamp: 1_700_000_005, offset: 4, bucket: 2, flagged: false },
+        Sample { timestamp: 1_700_000_009, offset: -3, bucket: 0, flagged: true },
+    ];
+
+    let bytes = serialize_pod_struct_columnar(&samples);
+    let decoded: Vec<Sample> = deserialize_pod_struct_columnar(&bytes).unwrap();
+    assert_eq!(decoded, samples.to_vec());
+}